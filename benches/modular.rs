@@ -0,0 +1,201 @@
+//! Compares Montgomery multiplication with the plain `%` path of `mod_mul` on 64-bit moduli.
+//!
+//! Run with `cargo bench`. Every benchmark is timed with `std::time::Instant` over a fixed
+//! amount of work, repeated a few times, and the fastest run is reported.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use eratosthenes::factorization::pollard_rho;
+use eratosthenes::modular::{gcd, mod_add, mod_mul, mod_pow, Montgomery};
+use eratosthenes::primality::miller_rabin;
+
+const RUNS: usize = 5;
+
+/// The largest prime below 2^64, a worst case for the size of the products.
+const MODULUS: u64 = 18_446_744_073_709_551_557;
+
+fn main() {
+    println!(
+        "{:<40} {:>12} {:>12} {:>8}",
+        "benchmark", "plain %", "montgomery", "speedup"
+    );
+
+    compare(
+        "1e7 chained multiplications",
+        || {
+            let mut x: u64 = 3;
+            for _ in 0..10_000_000 {
+                x = mod_mul(x, x, black_box(MODULUS));
+            }
+            x
+        },
+        || {
+            let montgomery: Montgomery = Montgomery::new(black_box(MODULUS));
+            let mut x: u64 = montgomery.to_montgomery(3);
+            for _ in 0..10_000_000 {
+                x = montgomery.mul(x, x);
+            }
+            montgomery.from_montgomery(x)
+        },
+    );
+
+    compare(
+        "1e5 exponentiations",
+        || {
+            (2..100_002u64)
+                .map(|base| mod_pow(base, MODULUS - 1, black_box(MODULUS)))
+                .fold(0, u64::wrapping_add)
+        },
+        || {
+            let montgomery: Montgomery = Montgomery::new(black_box(MODULUS));
+            (2..100_002u64)
+                .map(|base| {
+                    let power: u64 = montgomery.pow(montgomery.to_montgomery(base), MODULUS - 1);
+                    montgomery.from_montgomery(power)
+                })
+                .fold(0, u64::wrapping_add)
+        },
+    );
+
+    compare(
+        "Miller-Rabin on 1e5 odd numbers near 2^63",
+        || {
+            (0..100_000u64)
+                .filter(|offset| plain_miller_rabin(black_box((1 << 63) + 2 * offset + 1)))
+                .count() as u64
+        },
+        || {
+            (0..100_000u64)
+                .filter(|offset| miller_rabin(black_box((1 << 63) + 2 * offset + 1)))
+                .count() as u64
+        },
+    );
+
+    let semiprimes: Vec<u64> = [
+        (4_294_967_291, 4_294_967_279),
+        (2_147_483_647, 4_294_967_291),
+        (1_000_000_007, 998_244_353),
+        (999_999_937, 999_999_929),
+    ]
+    .iter()
+    .map(|&(p, q): &(u64, u64)| p * q)
+    .collect();
+    // Either factor may be found, so the smaller of the two is compared.
+    let smaller = |number: u64, factor: u64| factor.min(number / factor);
+    compare(
+        "Pollard rho on 4 semiprimes below 2^64",
+        || {
+            semiprimes
+                .iter()
+                .map(|&number| smaller(number, plain_pollard_rho(black_box(number))))
+                .sum()
+        },
+        || {
+            semiprimes
+                .iter()
+                .map(|&number| smaller(number, pollard_rho(black_box(number) as u128) as u64))
+                .sum()
+        },
+    );
+}
+
+/// Times both paths and prints them side by side, checking they agree.
+fn compare(name: &str, plain: impl Fn() -> u64, montgomery: impl Fn() -> u64) {
+    assert_eq!(plain(), montgomery(), "{name}: the two paths disagree");
+
+    let plain: Duration = time(plain);
+    let montgomery: Duration = time(montgomery);
+    println!(
+        "{:<40} {:>12.2?} {:>12.2?} {:>7.2}x",
+        name,
+        plain,
+        montgomery,
+        plain.as_secs_f64() / montgomery.as_secs_f64()
+    );
+}
+
+/// Returns the fastest of a few runs.
+fn time(work: impl Fn() -> u64) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start: Instant = Instant::now();
+            black_box(work());
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+/// The Miller–Rabin test as written on the plain `%` path, for comparison.
+fn plain_miller_rabin(number: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if number < 2 {
+        return false;
+    }
+    if let Some(&witness) = WITNESSES.iter().find(|&&witness| number % witness == 0) {
+        return number == witness;
+    }
+
+    let trailing_zeros: u32 = (number - 1).trailing_zeros();
+    let odd_part: u64 = (number - 1) >> trailing_zeros;
+    WITNESSES.iter().all(|&witness| {
+        let mut x: u64 = mod_pow(witness, odd_part, number);
+        if x == 1 || x == number - 1 {
+            return true;
+        }
+        for _ in 1..trailing_zeros {
+            x = mod_mul(x, x, number);
+            if x == number - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// Brent's variant of Pollard's rho as written on the plain `%` path, for comparison. It walks
+/// the same maps with the same batches as the library, only the multiplications differ.
+fn plain_pollard_rho(number: u64) -> u64 {
+    const BATCH_SIZE: u64 = 128;
+
+    (1..)
+        .find_map(|constant| {
+            let step = |x: u64| mod_add(mod_mul(x, x, number), constant, number);
+            let (mut x, mut y, mut saved): (u64, u64, u64) = (2, 2, 2);
+            let (mut product, mut divisor, mut length): (u64, u64, u64) = (1, 1, 1);
+
+            while divisor == 1 {
+                x = y;
+                for _ in 0..length {
+                    y = step(y);
+                }
+
+                let mut done: u64 = 0;
+                while done < length && divisor == 1 {
+                    saved = y;
+                    for _ in 0..BATCH_SIZE.min(length - done) {
+                        y = step(y);
+                        product = mod_mul(product, x.abs_diff(y), number);
+                    }
+                    divisor = gcd(product, number);
+                    done += BATCH_SIZE;
+                }
+
+                length *= 2;
+            }
+
+            if divisor == number {
+                loop {
+                    saved = step(saved);
+                    divisor = gcd(x.abs_diff(saved), number);
+                    if divisor != 1 {
+                        break;
+                    }
+                }
+            }
+            (divisor != number).then_some(divisor)
+        })
+        .unwrap()
+}
@@ -0,0 +1,304 @@
+use crate::factorization::Factorization;
+
+/// Calculates the sum of the `k`th powers of the divisors of a number.
+///
+/// Given an unsigned integer (`number`) and an exponent (`k`), this function returns
+/// `sigma_k(number)`, the sum of `d^k` over every divisor `d` of the number. It is computed
+/// multiplicatively, as the product of `1 + p^k + p^2k + ... + p^ek` over the prime powers `p^e`
+/// of the factorization. `sigma(n, 0)` is the number of divisors and `sigma(n, 1)` their sum.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned integer. The number whose divisors are summed.
+/// * `k` - The power the divisors are raised to.
+///
+/// # Panics
+///
+/// This function will panic if the input `number` is 0, or if the result does not fit in a
+/// `usize`. Use [`checked_sigma`] to handle overflow.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::sigma;
+///
+/// assert_eq!(sigma(12, 0), 6);
+/// assert_eq!(sigma(12, 1), 28);
+/// assert_eq!(sigma(12, 2), 210);
+/// ```
+pub fn sigma(number: usize, k: u32) -> usize {
+    checked_sigma(number, k).expect("eratosthenes::sigma overflows usize.")
+}
+
+/// Calculates the sum of the `k`th powers of the divisors of a number, returning `None` if the
+/// result does not fit in a `usize`.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned integer. The number whose divisors are summed.
+/// * `k` - The power the divisors are raised to.
+///
+/// # Panics
+///
+/// This function will panic if the input `number` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::checked_sigma;
+///
+/// assert_eq!(checked_sigma(12, 1), Some(28));
+/// assert_eq!(checked_sigma(12, 100), None);
+/// ```
+pub fn checked_sigma(number: usize, k: u32) -> Option<usize> {
+    if number == 0 {
+        panic!("eratosthenes::sigma cannot work with 0.");
+    }
+
+    let factorization: Factorization = Factorization::new(number as u128);
+    let mut result: usize = 1;
+    for &(prime, exponent) in factorization.prime_powers() {
+        let step: usize = (prime as usize).checked_pow(k)?;
+        let mut term: usize = 1;
+        let mut power: usize = 1;
+        for _ in 0..exponent {
+            power = power.checked_mul(step)?;
+            term = term.checked_add(power)?;
+        }
+        result = result.checked_mul(term)?;
+    }
+
+    Some(result)
+}
+
+/// Calculates the number of divisors of a number.
+///
+/// Given an unsigned integer (`number`), this function returns `tau(number)`, the number of its
+/// divisors including 1 and the number itself, computed as the product of `e + 1` over the prime
+/// powers `p^e` of the factorization.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned integer. The number whose divisors are counted.
+///
+/// # Panics
+///
+/// This function will panic if the input `number` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::divisor_count;
+///
+/// assert_eq!(divisor_count(1), 1);
+/// assert_eq!(divisor_count(28), 6);
+/// ```
+pub fn divisor_count(number: usize) -> usize {
+    sigma(number, 0)
+}
+
+/// Calculates the aliquot sum of a number, the sum of its proper divisors.
+///
+/// Given an unsigned integer (`number`), this function returns the sum of the divisors of the
+/// number other than the number itself, `sigma(number, 1) - number`.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned integer. The number whose proper divisors are summed.
+///
+/// # Panics
+///
+/// This function will panic if the input `number` is 0, or if the sum of its divisors does not
+/// fit in a `usize`. Use [`checked_aliquot_sum`] to handle overflow.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::aliquot_sum;
+///
+/// assert_eq!(aliquot_sum(1), 0);
+/// assert_eq!(aliquot_sum(220), 284);
+/// ```
+pub fn aliquot_sum(number: usize) -> usize {
+    checked_aliquot_sum(number).expect("eratosthenes::aliquot_sum overflows usize.")
+}
+
+/// Calculates the aliquot sum of a number, returning `None` if the sum of its divisors does not
+/// fit in a `usize`.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned integer. The number whose proper divisors are summed.
+///
+/// # Panics
+///
+/// This function will panic if the input `number` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::checked_aliquot_sum;
+///
+/// assert_eq!(checked_aliquot_sum(284), Some(220));
+/// assert_eq!(checked_aliquot_sum(usize::MAX - 1), None);
+/// ```
+pub fn checked_aliquot_sum(number: usize) -> Option<usize> {
+    checked_sigma(number, 1).map(|sum| sum - number)
+}
+
+/// Checks if a number is perfect, equal to the sum of its proper divisors.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned integer. The number to classify.
+///
+/// # Panics
+///
+/// This function will panic if the input `number` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::is_perfect;
+///
+/// assert!(is_perfect(28));
+/// assert!(!is_perfect(30));
+/// ```
+pub fn is_perfect(number: usize) -> bool {
+    checked_aliquot_sum(number) == Some(number)
+}
+
+/// Checks if a number is abundant, smaller than the sum of its proper divisors.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned integer. The number to classify.
+///
+/// # Panics
+///
+/// This function will panic if the input `number` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::is_abundant;
+///
+/// assert!(is_abundant(12));
+/// assert!(!is_abundant(28));
+/// ```
+pub fn is_abundant(number: usize) -> bool {
+    checked_aliquot_sum(number).map_or(true, |sum| sum > number)
+}
+
+/// Checks if a number is deficient, larger than the sum of its proper divisors.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned integer. The number to classify.
+///
+/// # Panics
+///
+/// This function will panic if the input `number` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::is_deficient;
+///
+/// assert!(is_deficient(1));
+/// assert!(is_deficient(13));
+/// assert!(!is_deficient(12));
+/// ```
+pub fn is_deficient(number: usize) -> bool {
+    checked_aliquot_sum(number).is_some_and(|sum| sum < number)
+}
+
+/// Checks if a number is amicable, part of a pair of distinct numbers where each is the sum of
+/// the proper divisors of the other.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned integer. The number to classify.
+///
+/// # Panics
+///
+/// This function will panic if the input `number` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::is_amicable;
+///
+/// assert!(is_amicable(220));
+/// assert!(is_amicable(284));
+/// assert!(!is_amicable(6));
+/// ```
+pub fn is_amicable(number: usize) -> bool {
+    match checked_aliquot_sum(number) {
+        Some(partner) if partner != number && partner != 0 => {
+            checked_aliquot_sum(partner) == Some(number)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn sigma_test() {
+        assert_eq!(sigma(1, 0), 1);
+        assert_eq!(sigma(1, 5), 1);
+        assert_eq!(sigma(28, 1), 56);
+        assert_eq!(sigma(10, 3), 1_134);
+        for number in 1..200 {
+            let divisors: Vec<usize> = crate::factors(number);
+            assert_eq!(sigma(number, 0), divisors.len());
+            assert_eq!(sigma(number, 1), divisors.iter().sum::<usize>());
+            assert_eq!(
+                sigma(number, 2),
+                divisors.iter().map(|d| d * d).sum::<usize>()
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn sigma_fail() {
+        sigma(0, 1);
+    }
+
+    #[test]
+    fn checked_sigma_test() {
+        assert_eq!(checked_sigma(2, 63), Some((1 << 63) + 1));
+        assert_eq!(checked_sigma(2, 64), None);
+        assert_eq!(checked_sigma(720_720, 4), None);
+    }
+
+    #[test]
+    fn divisor_count_test() {
+        assert_eq!(divisor_count(76_576_500), 576);
+        assert_eq!(divisor_count(999_999_000_001), 2);
+    }
+
+    #[test]
+    fn aliquot_sum_test() {
+        assert_eq!(aliquot_sum(12), 16);
+        assert_eq!(aliquot_sum(13), 1);
+        assert_eq!(checked_aliquot_sum(usize::MAX - 1), None);
+    }
+
+    #[test]
+    fn classification_test() {
+        let perfect: Vec<usize> = (1..10_000).filter(|&n| is_perfect(n)).collect();
+        assert_eq!(perfect, vec![6, 28, 496, 8_128]);
+        assert!(is_perfect(33_550_336));
+        assert_eq!((1..=30).filter(|&n| is_abundant(n)).count(), 5);
+        assert_eq!((1..=30).filter(|&n| is_deficient(n)).count(), 23);
+        assert!(is_amicable(1_184) && is_amicable(1_210));
+        assert!(!is_amicable(1));
+        assert!(!is_amicable(28));
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+
+use crate::factorization::Factorization;
+
+/// Calculates the Möbius function of a number.
+///
+/// Given an unsigned integer (`number`), this function returns `mu(number)`: 0 if the number is
+/// divisible by the square of a prime, otherwise 1 or -1 depending on whether it has an even or
+/// odd number of prime factors.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned integer. The number whose Möbius function is calculated.
+///
+/// # Panics
+///
+/// This function will panic if the input `number` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::mobius;
+///
+/// assert_eq!(mobius(1), 1);
+/// assert_eq!(mobius(30), -1);
+/// assert_eq!(mobius(12), 0);
+/// ```
+pub fn mobius(number: usize) -> i8 {
+    if number == 0 {
+        panic!("eratosthenes::mobius cannot work with 0.");
+    }
+
+    let factorization: Factorization = Factorization::new(number as u128);
+    if factorization
+        .prime_powers()
+        .iter()
+        .any(|&(_, exponent)| exponent > 1)
+    {
+        return 0;
+    }
+
+    match factorization.prime_powers().len() % 2 {
+        0 => 1,
+        _ => -1,
+    }
+}
+
+/// Calculates the Liouville function of a number.
+///
+/// Given an unsigned integer (`number`), this function returns `lambda(number)`, which is 1 if
+/// the number has an even count of prime factors counted with multiplicity, and -1 otherwise.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned integer. The number whose Liouville function is calculated.
+///
+/// # Panics
+///
+/// This function will panic if the input `number` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::liouville;
+///
+/// assert_eq!(liouville(1), 1);
+/// assert_eq!(liouville(12), -1);
+/// assert_eq!(liouville(36), 1);
+/// ```
+pub fn liouville(number: usize) -> i8 {
+    if number == 0 {
+        panic!("eratosthenes::liouville cannot work with 0.");
+    }
+
+    let factor_count: u32 = Factorization::new(number as u128)
+        .prime_powers()
+        .iter()
+        .map(|&(_, exponent)| exponent)
+        .sum();
+
+    match factor_count % 2 {
+        0 => 1,
+        _ => -1,
+    }
+}
+
+/// Builds a table of the Möbius function of every integer up to a limit.
+///
+/// Given the limit (`until`), this function returns a vector (`Vec`) whose entry at index `n` is
+/// `mu(n)`, for every `n` up to and including the limit. The entry at index 0 is 0. The sign of
+/// every multiple of a prime is flipped, and multiples of prime squares are zeroed.
+///
+/// # Arguments
+///
+/// * `until` - The largest integer whose Möbius function is calculated.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::mobius_sieve;
+///
+/// let table = mobius_sieve(10);
+/// assert_eq!(table, vec![0, 1, -1, -1, 0, -1, 1, -1, 0, 0, 1]);
+/// ```
+pub fn mobius_sieve(until: usize) -> Vec<i8> {
+    let mut table: Vec<i8> = vec![1; until + 1];
+    table[0] = 0;
+    if until < 2 {
+        return table;
+    }
+
+    for prime in crate::sequences::primes(until) {
+        for multiple in (prime..=until).step_by(prime) {
+            table[multiple] = -table[multiple];
+        }
+        if let Some(square) = prime.checked_mul(prime) {
+            for multiple in (square..=until).step_by(square) {
+                table[multiple] = 0;
+            }
+        }
+    }
+
+    table
+}
+
+/// Calculates the Mertens function, the sum of the Möbius function from 1 up to a limit.
+///
+/// Given the limit (`until`), this function returns `M(until) = mu(1) + mu(2) + ... +
+/// mu(until)`. The Möbius function is sieved up to about `until^(2/3)`, and larger values are
+/// found from the identity `M(x) = 1 - sum M(x / d)` over `2 <= d <= x`, grouping the `d` that
+/// share a quotient and memoizing the results. This takes about `O(until^(2/3))` time.
+///
+/// # Arguments
+///
+/// * `until` - The largest integer whose Möbius function is added.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::mertens;
+///
+/// assert_eq!(mertens(10), -1);
+/// assert_eq!(mertens(1_000_000_000), -222);
+/// ```
+pub fn mertens(until: usize) -> i64 {
+    let cube_root: usize = (until as f64).cbrt() as usize + 1;
+    let limit: usize = (cube_root * cube_root).min(until);
+
+    let mut prefix: Vec<i64> = Vec::with_capacity(limit + 1);
+    let mut sum: i64 = 0;
+    for value in mobius_sieve(limit) {
+        sum += value as i64;
+        prefix.push(sum);
+    }
+
+    let mut cache: HashMap<usize, i64> = HashMap::new();
+    mertens_cached(until, &prefix, &mut cache)
+}
+
+fn mertens_cached(until: usize, prefix: &[i64], cache: &mut HashMap<usize, i64>) -> i64 {
+    if until < prefix.len() {
+        return prefix[until];
+    }
+    if let Some(&value) = cache.get(&until) {
+        return value;
+    }
+
+    let mut result: i64 = 1;
+    let mut divisor: usize = 2;
+    while divisor <= until {
+        let quotient: usize = until / divisor;
+        let last: usize = until / quotient;
+        result -= (last - divisor + 1) as i64 * mertens_cached(quotient, prefix, cache);
+        divisor = last + 1;
+    }
+
+    cache.insert(until, result);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn mobius_test() {
+        assert_eq!(mobius(2), -1);
+        assert_eq!(mobius(6), 1);
+        assert_eq!(mobius(1_000_000_007 * 1_000_000_009), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mobius_fail() {
+        mobius(0);
+    }
+
+    #[test]
+    fn liouville_test() {
+        assert_eq!(liouville(2), -1);
+        assert_eq!(liouville(1024), 1);
+        assert_eq!(liouville(30), -1);
+    }
+
+    #[test]
+    fn mobius_sieve_test() {
+        assert_eq!(mobius_sieve(0), vec![0]);
+        let table: Vec<i8> = mobius_sieve(10_000);
+        for (number, &mu) in table.iter().enumerate().skip(1) {
+            assert_eq!(mu, mobius(number));
+        }
+    }
+
+    #[test]
+    fn mertens_test() {
+        assert_eq!(mertens(0), 0);
+        assert_eq!(mertens(1), 1);
+        assert_eq!(mertens(100), 1);
+        assert_eq!(mertens(1_000), 2);
+        assert_eq!(mertens(10_000), -23);
+        assert_eq!(
+            mertens(123_456),
+            mobius_sieve(123_456)
+                .iter()
+                .map(|&mu| mu as i64)
+                .sum::<i64>()
+        );
+        assert_eq!(mertens(10_000_000), 1_037);
+    }
+}
@@ -0,0 +1,30 @@
+/*!
+This module includes arithmetic functions of the integers, computed from their prime factorization.
+*/
+
+pub mod divisor_functions;
+pub mod mobius;
+pub mod tables;
+pub mod totient;
+
+pub use self::divisor_functions::aliquot_sum;
+pub use self::divisor_functions::checked_aliquot_sum;
+pub use self::divisor_functions::checked_sigma;
+pub use self::divisor_functions::divisor_count;
+pub use self::divisor_functions::is_abundant;
+pub use self::divisor_functions::is_amicable;
+pub use self::divisor_functions::is_deficient;
+pub use self::divisor_functions::is_perfect;
+pub use self::divisor_functions::sigma;
+
+pub use self::mobius::liouville;
+pub use self::mobius::mertens;
+pub use self::mobius::mobius;
+pub use self::mobius::mobius_sieve;
+
+pub use self::tables::divisor_count_table;
+pub use self::tables::divisor_sum_table;
+
+pub use self::totient::totient;
+pub use self::totient::totient_sieve;
+pub use self::totient::totient_sum;
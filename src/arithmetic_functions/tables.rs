@@ -0,0 +1,106 @@
+/// Builds a table of the number of divisors of every integer up to a limit.
+///
+/// Given the limit (`until`), this function returns a vector (`Vec`) whose entry at index `n` is
+/// the number of divisors of `n`, for every `n` up to and including the limit. The entry at index
+/// 0 is 0. The primes are generated with [`primes`](crate::sequences::primes) and every prime
+/// power is then spread over its multiples, which takes near-linear time.
+///
+/// # Arguments
+///
+/// * `until` - The largest integer whose divisors are counted.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::divisor_count_table;
+///
+/// let table = divisor_count_table(12);
+/// assert_eq!(table, vec![0, 1, 2, 2, 3, 2, 4, 2, 4, 3, 4, 2, 6]);
+/// ```
+pub fn divisor_count_table(until: usize) -> Vec<usize> {
+    multiplicative_table(until, |_, exponent, _| exponent as usize + 1)
+}
+
+/// Builds a table of the sum of divisors of every integer up to a limit.
+///
+/// Given the limit (`until`), this function returns a vector (`Vec`) whose entry at index `n` is
+/// the sum of the divisors of `n`, including `n` itself, for every `n` up to and including the
+/// limit. The entry at index 0 is 0. Subtract `n` to get the aliquot sum.
+///
+/// # Arguments
+///
+/// * `until` - The largest integer whose divisors are summed.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::divisor_sum_table;
+///
+/// let table = divisor_sum_table(12);
+/// assert_eq!(table, vec![0, 1, 3, 4, 7, 6, 12, 8, 15, 13, 18, 12, 28]);
+/// ```
+pub fn divisor_sum_table(until: usize) -> Vec<usize> {
+    multiplicative_table(until, |prime, _, power| (power * prime - 1) / (prime - 1))
+}
+
+/// Builds the table of a multiplicative function for every integer up to `until`.
+///
+/// The function is described by its values on prime powers: `value(p, e, p^e)` must return
+/// `f(p^e)`. Every integer `m` is multiplied by `f(p^e)` for the exact power `p^e` dividing it.
+/// The entry at index 0 is 0 and the entry at index 1 is 1.
+pub(crate) fn multiplicative_table(
+    until: usize,
+    value: impl Fn(usize, u32, usize) -> usize,
+) -> Vec<usize> {
+    let mut table: Vec<usize> = vec![1; until + 1];
+    table[0] = 0;
+    if until < 2 {
+        return table;
+    }
+
+    for prime in crate::sequences::primes(until) {
+        let mut power: usize = prime;
+        let mut exponent: u32 = 1;
+        loop {
+            let factor: usize = value(prime, exponent, power);
+            for multiple in (power..=until).step_by(power) {
+                if (multiple / power) % prime != 0 {
+                    table[multiple] *= factor;
+                }
+            }
+
+            match power.checked_mul(prime) {
+                Some(next) if next <= until => power = next,
+                _ => break,
+            }
+            exponent += 1;
+        }
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn divisor_count_table_test() {
+        assert_eq!(divisor_count_table(0), vec![0]);
+        assert_eq!(divisor_count_table(1), vec![0, 1]);
+        let table: Vec<usize> = divisor_count_table(10_000);
+        for (number, &count) in table.iter().enumerate().skip(1) {
+            assert_eq!(count, crate::arithmetic_functions::divisor_count(number));
+        }
+    }
+
+    #[test]
+    fn divisor_sum_table_test() {
+        assert_eq!(divisor_sum_table(2), vec![0, 1, 3]);
+        let table: Vec<usize> = divisor_sum_table(10_000);
+        for (number, &sum) in table.iter().enumerate().skip(1) {
+            assert_eq!(sum, crate::arithmetic_functions::sigma(number, 1));
+        }
+    }
+}
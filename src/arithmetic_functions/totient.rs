@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use crate::factorization::Factorization;
+
+/// Calculates Euler's totient of a number.
+///
+/// Given an unsigned integer (`number`), this function returns `phi(number)`, the count of
+/// integers from 1 to the number that are coprime to it. It is computed from the factorization
+/// as the product of `p^(e - 1) * (p - 1)` over the prime powers `p^e`.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned integer. The number whose totient is calculated.
+///
+/// # Panics
+///
+/// This function will panic if the input `number` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::totient;
+///
+/// assert_eq!(totient(1), 1);
+/// assert_eq!(totient(9), 6);
+/// assert_eq!(totient(36), 12);
+/// ```
+pub fn totient(number: usize) -> usize {
+    if number == 0 {
+        panic!("eratosthenes::totient cannot work with 0.");
+    }
+
+    Factorization::new(number as u128)
+        .prime_powers()
+        .iter()
+        .map(|&(prime, exponent)| {
+            let prime: usize = prime as usize;
+            prime.pow(exponent - 1) * (prime - 1)
+        })
+        .product()
+}
+
+/// Builds a table of Euler's totient of every integer up to a limit.
+///
+/// Given the limit (`until`), this function returns a vector (`Vec`) whose entry at index `n` is
+/// `phi(n)`, for every `n` up to and including the limit. The entry at index 0 is 0.
+///
+/// # Arguments
+///
+/// * `until` - The largest integer whose totient is calculated.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::totient_sieve;
+///
+/// let table = totient_sieve(10);
+/// assert_eq!(table, vec![0, 1, 1, 2, 2, 4, 2, 6, 4, 6, 4]);
+/// ```
+pub fn totient_sieve(until: usize) -> Vec<usize> {
+    super::tables::multiplicative_table(until, |prime, _, power| power - power / prime)
+}
+
+/// Calculates the sum of Euler's totient of every integer from 1 up to a limit.
+///
+/// Given the limit (`until`), this function returns `phi(1) + phi(2) + ... + phi(until)`. This
+/// sum minus one is the number of reduced proper fractions `a / b` with `b <= until`. The totient
+/// is sieved up to about `until^(2/3)`, and larger values are found from the identity
+/// `Phi(x) = x (x + 1) / 2 - sum Phi(x / d)` over `2 <= d <= x`, grouping the `d` that share a
+/// quotient and memoizing the results. This takes about `O(until^(2/3))` time and memory. The
+/// sum is returned as a `u128`, which holds it for any `usize` bound.
+///
+/// # Arguments
+///
+/// * `until` - The largest integer whose totient is added.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::arithmetic_functions::totient_sum;
+///
+/// assert_eq!(totient_sum(8) - 1, 21);
+/// assert_eq!(totient_sum(1_000_000_000), 303_963_551_173_008_414);
+/// assert_eq!(totient_sum(10_000_000_000), 30_396_355_092_886_216_366);
+/// ```
+pub fn totient_sum(until: usize) -> u128 {
+    let cube_root: usize = (until as f64).cbrt() as usize + 1;
+    let limit: usize = (cube_root * cube_root).min(until);
+
+    let mut prefix: Vec<u128> = Vec::with_capacity(limit + 1);
+    let mut sum: u128 = 0;
+    for value in totient_sieve(limit) {
+        sum += value as u128;
+        prefix.push(sum);
+    }
+
+    let mut cache: HashMap<usize, u128> = HashMap::new();
+    totient_sum_cached(until, &prefix, &mut cache)
+}
+
+fn totient_sum_cached(until: usize, prefix: &[u128], cache: &mut HashMap<usize, u128>) -> u128 {
+    if until < prefix.len() {
+        return prefix[until];
+    }
+    if let Some(&value) = cache.get(&until) {
+        return value;
+    }
+
+    let mut result: u128 = until as u128 * (until as u128 + 1) / 2;
+    let mut divisor: usize = 2;
+    while divisor <= until {
+        let quotient: usize = until / divisor;
+        let last: usize = until / quotient;
+        result -= (last - divisor + 1) as u128 * totient_sum_cached(quotient, prefix, cache);
+        divisor = last + 1;
+    }
+
+    cache.insert(until, result);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn totient_test() {
+        assert_eq!(totient(2), 1);
+        assert_eq!(totient(97), 96);
+        assert_eq!(totient(1_000_000), 400_000);
+        assert_eq!(
+            totient(1_000_000_007 * 1_000_000_009),
+            1_000_000_006 * 1_000_000_008
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn totient_fail() {
+        totient(0);
+    }
+
+    #[test]
+    fn totient_sieve_test() {
+        assert_eq!(totient_sieve(0), vec![0]);
+        let table: Vec<usize> = totient_sieve(10_000);
+        for (number, &phi) in table.iter().enumerate().skip(1) {
+            assert_eq!(phi, totient(number));
+        }
+    }
+
+    #[test]
+    fn totient_sum_test() {
+        assert_eq!(totient_sum(0), 0);
+        assert_eq!(totient_sum(1), 1);
+        assert_eq!(totient_sum(100), 3_044);
+        assert_eq!(
+            totient_sum(123_456),
+            totient_sieve(123_456).iter().sum::<usize>() as u128
+        );
+        assert_eq!(totient_sum(10_000_000), 30_396_356_427_242);
+    }
+}
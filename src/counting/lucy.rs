@@ -0,0 +1,127 @@
+/// The Fenwick tree covers the values up to `until^(2/3)` divided by this constant. A smaller tree
+/// costs a few more updates of the large values but has far fewer cache misses.
+const SMALL_LIMIT_DIVISOR: f64 = 16.0;
+
+/// Sums a completely multiplicative `weight` over the primes up to `until`, using
+/// Lucy_Hedgehog's dynamic programming.
+///
+/// `prefix(v)` must return the sum of `weight(n)` for `n` in `2..=v`. `S(v)` starts as that sum,
+/// and every prime `p` up to the square root of the bound removes the integers whose smallest
+/// prime factor is `p`, through `S(v) -= weight(p) * (S(v / p) - S(p - 1))`. Only the values
+/// `S(until / k)` are needed. The values up to about `until^(2/3)` are kept in a Fenwick tree
+/// that is sieved directly, and the larger ones in a plain array, so the sum takes about
+/// `O(until^(2/3))` time and memory.
+pub(crate) fn lucy_hedgehog(
+    until: usize,
+    weight: impl Fn(usize) -> u128,
+    prefix: impl Fn(usize) -> u128,
+) -> u128 {
+    if until < 2 {
+        return 0;
+    }
+
+    let root: usize = crate::isqrt(until as u128) as usize;
+    let small_limit: usize =
+        (((until as f64).powf(2.0 / 3.0) / SMALL_LIMIT_DIVISOR) as usize).clamp(root, until);
+
+    // large[k] holds S(until / k) for the k whose quotient is above the small limit.
+    let large_count: usize = until / (small_limit + 1);
+    let mut large: Vec<u128> = (0..=large_count)
+        .map(|k| until.checked_div(k).map_or(0, &prefix))
+        .collect();
+
+    let mut small: Fenwick = Fenwick::new(small_limit, &weight);
+    let mut crossed: Vec<bool> = vec![false; small_limit + 1];
+
+    for prime in 2..=root {
+        if crossed[prime] {
+            continue;
+        }
+
+        let prime_weight: u128 = weight(prime);
+        let below: u128 = small.prefix(prime - 1);
+        let square: usize = prime * prime;
+        for k in 1..=large_count.min(until / square) {
+            let multiple: usize = k * prime;
+            let quotient: u128 = if multiple <= large_count {
+                large[multiple]
+            } else {
+                small.prefix(until / multiple)
+            };
+            large[k] -= prime_weight * (quotient - below);
+        }
+
+        for multiple in (square..=small_limit).step_by(prime) {
+            if !crossed[multiple] {
+                crossed[multiple] = true;
+                small.subtract(multiple, weight(multiple));
+            }
+        }
+    }
+
+    if large_count >= 1 {
+        large[1]
+    } else {
+        small.prefix(until)
+    }
+}
+
+/// A Fenwick tree over `2..=limit`, summing the weights of the integers that have not been
+/// crossed out yet.
+struct Fenwick {
+    tree: Vec<u128>,
+}
+
+impl Fenwick {
+    fn new(limit: usize, weight: impl Fn(usize) -> u128) -> Self {
+        let mut tree: Vec<u128> = vec![0; limit + 1];
+        for index in 2..=limit {
+            tree[index] += weight(index);
+            let parent: usize = index + (index & index.wrapping_neg());
+            if parent <= limit {
+                tree[parent] += tree[index];
+            }
+        }
+
+        Self { tree }
+    }
+
+    fn subtract(&mut self, mut index: usize, value: u128) {
+        while index < self.tree.len() {
+            self.tree[index] -= value;
+            index += index & index.wrapping_neg();
+        }
+    }
+
+    fn prefix(&self, mut index: usize) -> u128 {
+        let mut sum: u128 = 0;
+        while index > 0 {
+            sum += self.tree[index];
+            index -= index & index.wrapping_neg();
+        }
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn lucy_hedgehog_test() {
+        let primes: Vec<usize> = crate::sequences::primes(5_000);
+        for until in 0..=5_000 {
+            let expected: u128 = primes
+                .iter()
+                .take_while(|&&prime| prime <= until)
+                .map(|&prime| (prime * prime) as u128)
+                .sum();
+            let squares = |v: usize| -> u128 {
+                let v: u128 = v as u128;
+                v * (v + 1) * (2 * v + 1) / 6 - 1
+            };
+            assert_eq!(lucy_hedgehog(until, |n| (n * n) as u128, squares), expected);
+        }
+    }
+}
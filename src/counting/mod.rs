@@ -0,0 +1,12 @@
+/*!
+This module includes functions counting primes up to bounds too large to sieve.
+*/
+
+mod lucy;
+pub mod prime_count;
+pub mod prime_sum;
+
+pub use self::prime_count::prime_count;
+
+pub use self::prime_sum::prime_power_sum;
+pub use self::prime_sum::prime_sum;
@@ -0,0 +1,56 @@
+/// Counts the prime numbers less than or equal to a bound.
+///
+/// Given the bound (`until`), this function returns `pi(until)` without listing the primes, using
+/// Lucy_Hedgehog's dynamic programming with the small values kept in a Fenwick tree. It takes
+/// about `O(until^(2/3))` time and memory.
+///
+/// # Arguments
+///
+/// * `until` - The bound up to which primes are counted, inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::counting::prime_count;
+///
+/// assert_eq!(prime_count(100), 25);
+/// assert_eq!(prime_count(10_000_000_000), 455_052_511);
+/// ```
+pub fn prime_count(until: usize) -> usize {
+    super::lucy::lucy_hedgehog(until, |_| 1, |v| v as u128 - 1) as usize
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn prime_count_test() {
+        assert_eq!(prime_count(0), 0);
+        assert_eq!(prime_count(1), 0);
+        assert_eq!(prime_count(2), 1);
+        assert_eq!(prime_count(3), 2);
+        assert_eq!(prime_count(10), 4);
+        assert_eq!(prime_count(100), 25);
+        assert_eq!(prime_count(1_000), 168);
+        assert_eq!(prime_count(10_000), 1_229);
+        assert_eq!(prime_count(100_000), 9_592);
+        assert_eq!(prime_count(1_000_000), 78_498);
+        assert_eq!(prime_count(10_000_000), 664_579);
+        assert_eq!(prime_count(100_000_000), 5_761_455);
+        assert_eq!(prime_count(1_000_000_000), 50_847_534);
+        assert_eq!(prime_count(10_000_000_000), 455_052_511);
+    }
+
+    #[test]
+    fn prime_count_agrees_with_sieve_test() {
+        let primes: Vec<usize> = crate::sequences::primes(20_000);
+        for until in 2..=20_000 {
+            assert_eq!(
+                prime_count(until),
+                primes.partition_point(|&prime| prime <= until)
+            );
+        }
+    }
+}
@@ -0,0 +1,136 @@
+/// Calculates the sum of the prime numbers less than or equal to a bound.
+///
+/// Given the bound (`until`), this function returns the sum of every prime up to and including
+/// the bound, without listing the primes. It runs the same Lucy_Hedgehog dynamic programming as
+/// [`prime_count`](super::prime_count), weighting every integer by itself. The result is a
+/// `u128`, which holds the sum for any `usize` bound.
+///
+/// # Arguments
+///
+/// * `until` - The bound up to which primes are added, inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::counting::prime_sum;
+///
+/// assert_eq!(prime_sum(10), 17);
+/// assert_eq!(prime_sum(1_000_000_000), 24_739_512_092_254_535);
+/// ```
+pub fn prime_sum(until: usize) -> u128 {
+    prime_power_sum(until, 1)
+}
+
+/// Calculates the sum of the `k`th powers of the prime numbers less than or equal to a bound.
+///
+/// Given the bound (`until`) and the exponent (`k`), this function returns the sum of `p^k` over
+/// every prime `p` up to and including the bound. `k = 0` counts the primes and `k = 1` adds them.
+///
+/// # Arguments
+///
+/// * `until` - The bound up to which primes are added, inclusive.
+/// * `k` - The power the primes are raised to.
+///
+/// # Panics
+///
+/// This function will panic if the sum of `n^k` over the integers up to the bound does not fit
+/// in a `u128`.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::counting::prime_power_sum;
+///
+/// assert_eq!(prime_power_sum(10, 2), 4 + 9 + 25 + 49);
+/// assert_eq!(prime_power_sum(1_000_000, 0), 78_498);
+/// ```
+pub fn prime_power_sum(until: usize, k: u32) -> u128 {
+    super::lucy::lucy_hedgehog(
+        until,
+        |n| (n as u128).pow(k),
+        |v| power_sum(v as u128, k) - 1,
+    )
+}
+
+/// Returns `1^k + 2^k + ... + v^k`, through the identity `sum n^k = sum_j S(k, j) j! C(v + 1,
+/// j + 1)` with `S` the Stirling numbers of the second kind.
+fn power_sum(v: u128, k: u32) -> u128 {
+    if k == 0 {
+        return v;
+    }
+
+    // stirling[j] holds S(k, j), built row by row from S(n, j) = j S(n - 1, j) + S(n - 1, j - 1).
+    let mut stirling: Vec<u128> = vec![0; k as usize + 1];
+    stirling[0] = 1;
+    for row in 1..=k as usize {
+        for j in (1..=row).rev() {
+            stirling[j] = j as u128 * stirling[j] + stirling[j - 1];
+        }
+        stirling[0] = 0;
+    }
+
+    let overflow = || -> u128 { panic!("eratosthenes::prime_power_sum overflows u128.") };
+
+    let mut total: u128 = 0;
+    let mut binomial: u128 = v + 1;
+    let mut factorial: u128 = 1;
+    for (j, &count) in stirling.iter().enumerate().skip(1) {
+        if v + 1 < j as u128 + 1 {
+            break;
+        }
+        binomial = binomial
+            .checked_mul(v + 1 - j as u128)
+            .unwrap_or_else(overflow)
+            / (j as u128 + 1);
+        factorial *= j as u128;
+        let term: u128 = count
+            .checked_mul(factorial)
+            .and_then(|product| product.checked_mul(binomial))
+            .unwrap_or_else(overflow);
+        total = total.checked_add(term).unwrap_or_else(overflow);
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn prime_sum_test() {
+        assert_eq!(prime_sum(0), 0);
+        assert_eq!(prime_sum(2), 2);
+        assert_eq!(prime_sum(100), 1_060);
+        assert_eq!(prime_sum(2_000_000), 142_913_828_922);
+        assert_eq!(
+            prime_sum(123_456),
+            crate::sequences::primes(123_456)
+                .iter()
+                .map(|&prime| prime as u128)
+                .sum::<u128>()
+        );
+    }
+
+    #[test]
+    fn prime_power_sum_test() {
+        assert_eq!(prime_power_sum(100, 0), 25);
+        assert_eq!(
+            prime_power_sum(10_000, 3),
+            crate::sequences::primes(10_000)
+                .iter()
+                .map(|&prime| (prime as u128).pow(3))
+                .sum::<u128>()
+        );
+    }
+
+    #[test]
+    fn power_sum_test() {
+        for k in 0..6 {
+            for v in 0..50u128 {
+                assert_eq!(power_sum(v, k), (1..=v).map(|n| n.pow(k)).sum::<u128>());
+            }
+        }
+    }
+}
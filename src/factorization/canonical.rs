@@ -0,0 +1,250 @@
+use std::fmt;
+use std::ops::Mul;
+
+/// The canonical factorization of a positive integer into prime powers.
+///
+/// The prime powers are stored as `(prime, exponent)` pairs, ordered by ascending prime, with
+/// every exponent at least 1. The number 1 is represented by an empty factorization.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::factorization::Factorization;
+///
+/// let factorization = Factorization::new(360);
+/// assert_eq!(factorization.prime_powers(), &[(2, 3), (3, 2), (5, 1)]);
+/// assert_eq!(factorization.to_string(), "2^3 * 3^2 * 5");
+/// assert_eq!(factorization.value(), 360);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Factorization {
+    prime_powers: Vec<(u128, u32)>,
+}
+
+impl Factorization {
+    /// Factorizes a positive integer.
+    ///
+    /// The prime factors are found with [`prime_factors`](super::prime_factors), then grouped
+    /// into prime powers.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the input `number` is 0.
+    pub fn new(number: u128) -> Self {
+        if number == 0 {
+            panic!("eratosthenes::factorization::Factorization cannot work with 0.");
+        }
+
+        Self::from_prime_factors(super::prime_factors(number))
+    }
+
+    /// Groups a list of primes, in any order and with repetition, into a factorization.
+    pub(crate) fn from_prime_factors(mut primes: Vec<u128>) -> Self {
+        primes.sort_unstable();
+
+        let mut prime_powers: Vec<(u128, u32)> = Vec::new();
+        for prime in primes {
+            match prime_powers.last_mut() {
+                Some((last, exponent)) if *last == prime => *exponent += 1,
+                _ => prime_powers.push((prime, 1)),
+            }
+        }
+
+        Self { prime_powers }
+    }
+
+    /// Returns the `(prime, exponent)` pairs, ordered by ascending prime.
+    pub fn prime_powers(&self) -> &[(u128, u32)] {
+        &self.prime_powers
+    }
+
+    /// Returns the distinct prime factors in ascending order.
+    pub fn primes(&self) -> impl Iterator<Item = u128> + '_ {
+        self.prime_powers.iter().map(|&(prime, _)| prime)
+    }
+
+    /// Returns the exponent of `prime` in the factorization, 0 if it does not divide the number.
+    pub fn exponent(&self, prime: u128) -> u32 {
+        match self
+            .prime_powers
+            .binary_search_by_key(&prime, |&(factor, _)| factor)
+        {
+            Ok(index) => self.prime_powers[index].1,
+            Err(_) => 0,
+        }
+    }
+
+    /// Returns `true` if this is the factorization of 1.
+    pub fn is_one(&self) -> bool {
+        self.prime_powers.is_empty()
+    }
+
+    /// Multiplies the prime powers back into the integer they factorize.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the integer does not fit in a `u128`.
+    pub fn value(&self) -> u128 {
+        self.checked_value()
+            .expect("eratosthenes::factorization::Factorization value overflows u128.")
+    }
+
+    /// Multiplies the prime powers back into the integer they factorize, returning `None` if it
+    /// does not fit in a `u128`.
+    pub fn checked_value(&self) -> Option<u128> {
+        self.prime_powers
+            .iter()
+            .try_fold(1u128, |product, &(prime, exponent)| {
+                product.checked_mul(prime.checked_pow(exponent)?)
+            })
+    }
+
+    /// Returns the factorization of the greatest common divisor of both numbers, the primes they
+    /// share with the smaller of the two exponents.
+    pub fn gcd(&self, other: &Factorization) -> Factorization {
+        self.merge(other, |a, b| a.min(b))
+    }
+
+    /// Returns the factorization of the least common multiple of both numbers, every prime of
+    /// either with the larger of the two exponents.
+    pub fn lcm(&self, other: &Factorization) -> Factorization {
+        self.merge(other, |a, b| a.max(b))
+    }
+
+    /// Combines the exponents of both factorizations prime by prime, with a missing prime counting
+    /// as exponent 0. Primes whose combined exponent is 0 are left out.
+    fn merge(&self, other: &Factorization, combine: impl Fn(u32, u32) -> u32) -> Factorization {
+        let mut prime_powers: Vec<(u128, u32)> = Vec::new();
+        let mut left = self.prime_powers.iter().peekable();
+        let mut right = other.prime_powers.iter().peekable();
+
+        loop {
+            let (prime, exponent) = match (left.peek(), right.peek()) {
+                (Some(&&(a, x)), Some(&&(b, y))) if a == b => {
+                    left.next();
+                    right.next();
+                    (a, combine(x, y))
+                }
+                (Some(&&(a, x)), Some(&&(b, _))) if a < b => {
+                    left.next();
+                    (a, combine(x, 0))
+                }
+                (_, Some(&&(b, y))) => {
+                    right.next();
+                    (b, combine(0, y))
+                }
+                (Some(&&(a, x)), None) => {
+                    left.next();
+                    (a, combine(x, 0))
+                }
+                (None, None) => break,
+            };
+
+            if exponent > 0 {
+                prime_powers.push((prime, exponent));
+            }
+        }
+
+        Factorization { prime_powers }
+    }
+}
+
+impl Mul for &Factorization {
+    type Output = Factorization;
+
+    fn mul(self, other: &Factorization) -> Factorization {
+        self.merge(other, |a, b| a + b)
+    }
+}
+
+impl Mul for Factorization {
+    type Output = Factorization;
+
+    fn mul(self, other: Factorization) -> Factorization {
+        &self * &other
+    }
+}
+
+impl fmt::Display for Factorization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_one() {
+            return write!(f, "1");
+        }
+
+        for (index, &(prime, exponent)) in self.prime_powers.iter().enumerate() {
+            if index > 0 {
+                write!(f, " * ")?;
+            }
+            match exponent {
+                1 => write!(f, "{}", prime)?,
+                _ => write!(f, "{}^{}", prime, exponent)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Factorization {
+                fn from(number: $integer) -> Self {
+                    Factorization::new(number as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn factorization_test() {
+        assert!(Factorization::new(1).is_one());
+        assert_eq!(Factorization::new(1).to_string(), "1");
+        assert_eq!(Factorization::new(12).prime_powers(), &[(2, 2), (3, 1)]);
+        assert_eq!(Factorization::new(12).to_string(), "2^2 * 3");
+        assert_eq!(Factorization::from(1024u32).to_string(), "2^10");
+        assert_eq!(
+            Factorization::from(u64::MAX).to_string(),
+            "3 * 5 * 17 * 257 * 641 * 65537 * 6700417"
+        );
+        assert_eq!(Factorization::new(360).exponent(3), 2);
+        assert_eq!(Factorization::new(360).exponent(7), 0);
+        assert_eq!(
+            Factorization::new(360).primes().collect::<Vec<u128>>(),
+            vec![2, 3, 5]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn factorization_fail() {
+        Factorization::new(0);
+    }
+
+    #[test]
+    fn value_test() {
+        assert_eq!(Factorization::new(1).value(), 1);
+        assert_eq!(Factorization::new(u128::MAX).value(), u128::MAX);
+        let square: Factorization = &Factorization::new(u128::MAX) * &Factorization::new(3);
+        assert_eq!(square.checked_value(), None);
+    }
+
+    #[test]
+    fn arithmetic_test() {
+        let a: Factorization = Factorization::new(360);
+        let b: Factorization = Factorization::new(84);
+        assert_eq!((&a * &b).value(), 30_240);
+        assert_eq!(a.gcd(&b).value(), 12);
+        assert_eq!(a.lcm(&b).value(), 2_520);
+        assert_eq!(a.clone() * Factorization::new(1), a);
+        assert!(Factorization::new(9).gcd(&Factorization::new(10)).is_one());
+    }
+}
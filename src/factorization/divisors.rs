@@ -0,0 +1,161 @@
+use super::Factorization;
+
+impl Factorization {
+    /// Generates every divisor of the factorized number, in no particular order.
+    ///
+    /// The divisors are built prime power by prime power, so the work is proportional to the
+    /// number of divisors rather than to the number itself.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the factorized number does not fit in a `u128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eratosthenes::factorization::Factorization;
+    ///
+    /// let mut divisors = Factorization::new(12).divisors();
+    /// divisors.sort();
+    /// assert_eq!(divisors, vec![1, 2, 3, 4, 6, 12]);
+    /// ```
+    pub fn divisors(&self) -> Vec<u128> {
+        self.assert_fits();
+
+        let mut divisors: Vec<u128> = vec![1];
+        for &(prime, exponent) in self.prime_powers() {
+            let count: usize = divisors.len();
+            let mut power: u128 = 1;
+            for _ in 0..exponent {
+                power *= prime;
+                for index in 0..count {
+                    divisors.push(divisors[index] * power);
+                }
+            }
+        }
+
+        divisors
+    }
+
+    /// Generates every divisor of the factorized number in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the factorized number does not fit in a `u128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eratosthenes::factorization::Factorization;
+    ///
+    /// assert_eq!(Factorization::new(28).sorted_divisors(), vec![1, 2, 4, 7, 14, 28]);
+    /// ```
+    pub fn sorted_divisors(&self) -> Vec<u128> {
+        let mut divisors: Vec<u128> = self.divisors();
+        divisors.sort_unstable();
+        divisors
+    }
+
+    /// Returns a lazy iterator over the divisors of the factorized number, in no particular
+    /// order, so the search can stop early without building the full list.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the factorized number does not fit in a `u128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eratosthenes::factorization::Factorization;
+    ///
+    /// let factorization = Factorization::new(720_720);
+    /// let first_large = factorization.divisors_iter().find(|&divisor| divisor > 1_000);
+    /// assert!(first_large.is_some());
+    /// ```
+    pub fn divisors_iter(&self) -> Divisors<'_> {
+        self.assert_fits();
+
+        Divisors {
+            prime_powers: self.prime_powers(),
+            exponents: vec![0; self.prime_powers().len()],
+            current: Some(1),
+        }
+    }
+
+    /// Panics if the factorized number overflows a `u128`. Every divisor fits once the number
+    /// itself does, so the divisors can then be multiplied out without checks.
+    fn assert_fits(&self) {
+        if self.checked_value().is_none() {
+            panic!("eratosthenes::factorization::Factorization value overflows u128.");
+        }
+    }
+}
+
+/// A lazy iterator over the divisors of a [`Factorization`].
+///
+/// The divisors are visited by counting through the exponent of every prime like the digits of
+/// a mixed-radix number, so each step costs a few multiplications and no allocation.
+#[derive(Clone, Debug)]
+pub struct Divisors<'a> {
+    prime_powers: &'a [(u128, u32)],
+    exponents: Vec<u32>,
+    current: Option<u128>,
+}
+
+impl Iterator for Divisors<'_> {
+    type Item = u128;
+
+    fn next(&mut self) -> Option<u128> {
+        let divisor: u128 = self.current?;
+
+        let mut next: u128 = divisor;
+        self.current = None;
+        for (index, &(prime, exponent)) in self.prime_powers.iter().enumerate() {
+            if self.exponents[index] < exponent {
+                self.exponents[index] += 1;
+                self.current = Some(next * prime);
+                break;
+            }
+            next /= prime.pow(exponent);
+            self.exponents[index] = 0;
+        }
+
+        Some(divisor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn divisors_test() {
+        assert_eq!(Factorization::new(1).divisors(), vec![1]);
+        assert_eq!(Factorization::new(13).sorted_divisors(), vec![1, 13]);
+
+        let brute_force = |number: u128| (1..=number).filter(|d| number % d == 0).collect();
+        // Small numbers, prime powers and highly composite numbers.
+        let numbers = (1..=1_000).chain([1_024, 2_187, 15_625, 16_807, 27_720, 55_440, 720_720]);
+        for number in numbers {
+            let expected: Vec<u128> = brute_force(number);
+            assert_eq!(Factorization::new(number).sorted_divisors(), expected);
+        }
+        assert_eq!(Factorization::new(u128::MAX).divisors().len(), 512);
+    }
+
+    #[test]
+    fn divisors_iter_test() {
+        for number in 1..500u128 {
+            let factorization: Factorization = Factorization::new(number);
+            let mut lazy: Vec<u128> = factorization.divisors_iter().collect();
+            lazy.sort_unstable();
+            assert_eq!(lazy, factorization.sorted_divisors());
+        }
+        assert_eq!(Factorization::new(1).divisors_iter().count(), 1);
+        assert_eq!(
+            Factorization::new(720_720).divisors_iter().take(3).count(),
+            3
+        );
+    }
+}
@@ -0,0 +1,14 @@
+/*!
+This module includes integer factorization for numbers too large for trial division.
+*/
+
+pub mod canonical;
+pub mod divisors;
+pub mod pollard_rho;
+
+pub use self::canonical::Factorization;
+
+pub use self::divisors::Divisors;
+
+pub use self::pollard_rho::pollard_rho;
+pub use self::pollard_rho::prime_factors;
@@ -0,0 +1,283 @@
+use std::sync::OnceLock;
+
+use crate::modular::montgomery::Montgomery128;
+use crate::modular::{gcd, Montgomery};
+use crate::primality::baillie_psw;
+use crate::sequences::primes::small_primes;
+
+/// Prime factors below this limit are removed by trial division before Pollard's rho is used.
+const TRIAL_DIVISION_LIMIT: usize = 1_000;
+
+/// Number of steps whose differences are multiplied together before taking a gcd in Brent's
+/// cycle detection.
+const BATCH_SIZE: u128 = 128;
+
+/// Calculates the prime factors of a given number, with multiplicity.
+///
+/// Given an unsigned integer (`number`), this function returns a vector (`Vec`) containing every
+/// prime factor of the number in ascending order, each repeated as many times as it divides the
+/// number. Small factors are removed by trial division with the primes below 1000, the remaining
+/// cofactor is split with Brent's variant of [`pollard_rho`] and each part is checked with the
+/// [`baillie_psw`](crate::primality::baillie_psw) test.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned 128-bit integer. The number to factorize.
+///
+/// # Panics
+///
+/// This function will panic if the input `number` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::factorization::prime_factors;
+///
+/// assert_eq!(prime_factors(1), vec![]);
+/// assert_eq!(prime_factors(360), vec![2, 2, 2, 3, 3, 5]);
+/// assert_eq!(prime_factors(600_851_475_143), vec![71, 839, 1471, 6857]);
+/// ```
+pub fn prime_factors(number: u128) -> Vec<u128> {
+    if number == 0 {
+        panic!("eratosthenes::factorization::prime_factors cannot work with 0.");
+    }
+
+    let mut factors: Vec<u128> = Vec::new();
+    let mut remaining: u128 = number;
+
+    for &prime in trial_primes() {
+        if prime * prime > remaining {
+            break;
+        }
+        while remaining % prime == 0 {
+            factors.push(prime);
+            remaining /= prime;
+        }
+    }
+
+    split(remaining, &mut factors);
+    factors.sort_unstable();
+    factors
+}
+
+/// Returns the primes below [`TRIAL_DIVISION_LIMIT`], sieved on first use and then shared
+/// between threads without locking.
+fn trial_primes() -> &'static [u128] {
+    static PRIMES: OnceLock<Vec<u128>> = OnceLock::new();
+
+    PRIMES.get_or_init(|| {
+        small_primes(TRIAL_DIVISION_LIMIT - 1)
+            .into_iter()
+            .map(|prime| prime as u128)
+            .collect()
+    })
+}
+
+/// Finds a non-trivial factor of a composite number using Brent's variant of Pollard's rho.
+///
+/// Given a composite unsigned integer (`number`), this function iterates `x -> x^2 + c` modulo
+/// the number, using Brent's cycle detection with batched gcd computations, and returns a factor
+/// strictly between 1 and the number. The constant `c` is increased until a factor is found.
+/// The returned factor is not necessarily prime.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned 128-bit integer. The composite number to split.
+///
+/// # Panics
+///
+/// This function will panic if the input `number` is less than 4 or prime.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::factorization::pollard_rho;
+///
+/// let factor = pollard_rho(10_000_019 * 100_000_007);
+/// assert!(factor == 10_000_019 || factor == 100_000_007);
+/// ```
+pub fn pollard_rho(number: u128) -> u128 {
+    if number < 4 || baillie_psw(number) {
+        panic!("eratosthenes::factorization::pollard_rho can only split composite numbers.");
+    }
+
+    if number % 2 == 0 {
+        return 2;
+    }
+
+    let factor: Option<u128> = if number <= u64::MAX as u128 {
+        // The iteration runs in Montgomery form, where `x -> x^2 + c` is another polynomial map
+        // and the differences keep their common factors with the number. Above 64 bits the same
+        // is done with 128-bit Montgomery products.
+        let montgomery: Montgomery = Montgomery::new(number as u64);
+        (1..).find_map(|constant| {
+            let constant: u64 = montgomery.to_montgomery(constant);
+            brent(
+                number,
+                |x| montgomery.add(montgomery.mul(x as u64, x as u64), constant) as u128,
+                |a, b| montgomery.mul(a as u64, b as u64) as u128,
+            )
+        })
+    } else {
+        let montgomery: Montgomery128 = Montgomery128::new(number);
+        (1..).find_map(|constant| {
+            let constant: u128 = montgomery.to_montgomery(constant);
+            brent(
+                number,
+                |x| montgomery.add(montgomery.mul(x, x), constant),
+                |a, b| montgomery.mul(a, b),
+            )
+        })
+    };
+
+    factor.expect("Some constant always yields a factor of a composite number.")
+}
+
+/// Recursively splits `number` into primes, appending them to `factors`.
+fn split(number: u128, factors: &mut Vec<u128>) {
+    if number == 1 {
+        return;
+    }
+
+    if baillie_psw(number) {
+        factors.push(number);
+        return;
+    }
+
+    let factor: u128 = pollard_rho(number);
+    split(factor, factors);
+    split(number / factor, factors);
+}
+
+/// Runs Brent's cycle detection on the map `step` modulo the odd `number`, multiplying
+/// differences with `mul`, and returns a non-trivial factor or `None` if this map only finds the
+/// number itself.
+fn brent(
+    number: u128,
+    step: impl Fn(u128) -> u128,
+    mul: impl Fn(u128, u128) -> u128,
+) -> Option<u128> {
+    let mut y: u128 = 2;
+    let mut x: u128 = y;
+    let mut saved: u128 = y;
+    let mut product: u128 = 1;
+    let mut divisor: u128 = 1;
+    let mut length: u128 = 1;
+
+    while divisor == 1 {
+        x = y;
+        for _ in 0..length {
+            y = step(y);
+        }
+
+        let mut done: u128 = 0;
+        while done < length && divisor == 1 {
+            saved = y;
+            for _ in 0..BATCH_SIZE.min(length - done) {
+                y = step(y);
+                product = mul(product, x.abs_diff(y));
+            }
+            divisor = gcd(product, number);
+            done += BATCH_SIZE;
+        }
+
+        length *= 2;
+    }
+
+    if divisor == number {
+        // The batch overshot the cycle, walk it again one step at a time.
+        loop {
+            saved = step(saved);
+            divisor = gcd(x.abs_diff(saved), number);
+            if divisor != 1 {
+                break;
+            }
+        }
+    }
+
+    if divisor == number {
+        None
+    } else {
+        Some(divisor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn prime_factors_test() {
+        assert_eq!(prime_factors(1), vec![]);
+        assert_eq!(prime_factors(2), vec![2]);
+        assert_eq!(prime_factors(1024), vec![2; 10]);
+        assert_eq!(prime_factors(99_651_999), vec![3, 33_217_333]);
+        assert_eq!(
+            prime_factors(10_000_019 * 100_000_007),
+            vec![10_000_019, 100_000_007]
+        );
+        assert_eq!(
+            prime_factors(1_000_000_007 * 1_000_000_009),
+            vec![1_000_000_007, 1_000_000_009]
+        );
+        assert_eq!(
+            prime_factors((1 << 67) - 1),
+            vec![193_707_721, 761_838_257_287]
+        );
+        assert_eq!(
+            prime_factors(u128::MAX),
+            vec![
+                3,
+                5,
+                17,
+                257,
+                641,
+                65_537,
+                274_177,
+                6_700_417,
+                67_280_421_310_721
+            ]
+        );
+        assert_eq!(
+            prime_factors(((1 << 64) + 13) * 1_000_003),
+            vec![1_000_003, (1 << 64) + 13]
+        );
+        assert_eq!(prime_factors(1_000_003 * 1_000_003), vec![1_000_003; 2]);
+
+        // Factorizing must not wait for the shared sieve.
+        let _guard = crate::sieve::shared_sieve();
+        assert_eq!(prime_factors(12), vec![2, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn prime_factors_fail() {
+        prime_factors(0);
+    }
+
+    #[test]
+    fn pollard_rho_test() {
+        assert_eq!(pollard_rho(4), 2);
+        let factor: u128 = pollard_rho(1_000_003 * 1_000_033);
+        assert!(factor == 1_000_003 || factor == 1_000_033);
+    }
+
+    #[test]
+    fn pollard_rho_wide_test() {
+        // A semiprime above 2^64 with factors near 2^40 needs about a million steps, which the
+        // 128-bit Montgomery products keep well within the budget even without optimizations.
+        let start: std::time::Instant = std::time::Instant::now();
+        assert_eq!(
+            prime_factors(1_099_511_627_791 * 1_099_511_628_401),
+            vec![1_099_511_627_791, 1_099_511_628_401]
+        );
+        assert!(start.elapsed() < std::time::Duration::from_secs(10));
+    }
+
+    #[test]
+    #[should_panic]
+    fn pollard_rho_fail() {
+        pollard_rho(1_000_003);
+    }
+}
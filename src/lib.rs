@@ -8,7 +8,13 @@ Note that this library mostly works with `usize` integers, tested on 64 bit syst
 
 use core::panic;
 
+pub mod arithmetic_functions;
+pub mod counting;
+pub mod factorization;
+pub mod modular;
+pub mod primality;
 pub mod sequences;
+pub mod sieve;
 
 /// Cleans an array of unsigned integers by removing duplicate values and sorting it.
 ///
@@ -33,6 +39,7 @@ pub mod sequences;
 /// let cleaned_array = array_clean(&input);
 /// assert_eq!(cleaned_array, vec![2, 4, 5, 7]);
 /// ```
+#[allow(clippy::ptr_arg)]
 pub fn array_clean(array: &Vec<usize>) -> Vec<usize> {
     if array.is_empty() {
         panic!("eratosthenes::array_clean cannot work on an empty array.");
@@ -66,6 +73,7 @@ pub fn array_clean(array: &Vec<usize>) -> Vec<usize> {
 /// let merged_array = array_merge(&input1, &input2);
 /// assert_eq!(merged_array, vec![1, 2, 3, 4, 5, 6]);
 /// ```
+#[allow(clippy::ptr_arg)]
 pub fn array_merge(array1: &Vec<usize>, array2: &Vec<usize>) -> Vec<usize> {
     let mut storage: Vec<usize> = Vec::new();
     storage.extend_from_slice(array1);
@@ -77,12 +85,17 @@ pub fn array_merge(array1: &Vec<usize>, array2: &Vec<usize>) -> Vec<usize> {
 ///
 /// Given an unsigned integer (`number`), this function returns a vector (`Vec`) containing all
 /// the factors of the input number. The factors are the positive integers that evenly divide the
-/// given number. The vector includes 1 and the number itself.
+/// given number. The vector includes 1 and the number itself, in ascending order. The factors are
+/// generated from the prime factorization of the number.
 ///
 /// # Arguments
 ///
 /// * `number` - An unsigned integer. The number for which factors are calculated.
 ///
+/// # Panics
+///
+/// This function will panic if the input `number` is 0.
+///
 /// # Examples
 ///
 /// ```
@@ -93,20 +106,15 @@ pub fn array_merge(array1: &Vec<usize>, array2: &Vec<usize>) -> Vec<usize> {
 /// assert_eq!(factor_sequence, vec![1, 2, 3, 4, 6, 12]);
 /// ```
 pub fn factors(number: usize) -> Vec<usize> {
-    if number == 1 {
-        return vec![1];
+    if number == 0 {
+        panic!("eratosthenes::factors cannot work with 0.");
     };
 
-    let mut storage: Vec<usize> = Vec::new();
-    storage.push(1);
-    for divisor in 2..number / 2 + 1 {
-        if number % divisor == 0 {
-            storage.push(divisor)
-        }
-    }
-    storage.push(number);
-
-    storage
+    factorization::Factorization::new(number as u128)
+        .sorted_divisors()
+        .into_iter()
+        .map(|divisor| divisor as usize)
+        .collect()
 }
 
 /// Calculates the mean value of a vector of unsigned integers.
@@ -133,6 +141,7 @@ pub fn factors(number: usize) -> Vec<usize> {
 /// let mean = mean_usize(&input);
 /// assert_eq!(mean, 3.0);
 /// ```
+#[allow(clippy::ptr_arg, clippy::let_and_return, clippy::doc_lazy_continuation)]
 pub fn mean_usize(array: &Vec<usize>) -> f64 {
     if array.is_empty() {
         panic!("eratosthenes::mean_usize cannot work on an empty array.");
@@ -168,6 +177,7 @@ pub fn mean_usize(array: &Vec<usize>) -> f64 {
 /// let mean = mean_f64(&input);
 /// assert_eq!(mean, 3.0);
 /// ```
+#[allow(clippy::ptr_arg, clippy::let_and_return, clippy::doc_lazy_continuation)]
 pub fn mean_f64(array: &Vec<f64>) -> f64 {
     if array.is_empty() {
         panic!("eratosthenes::mean_usize cannot work on an empty array.");
@@ -184,6 +194,7 @@ pub fn mean_f64(array: &Vec<f64>) -> f64 {
 /// Given an unsigned integer (`number`), this function returns a vector (`Vec`) containing all
 /// the prime factors of the input number. Prime factors are the prime numbers that divide the
 /// given number without leaving a remainder. The vector includes the prime factors in ascending order.
+/// This is the list of primes of [`factorization::Factorization`], without the exponents.
 ///
 /// # Arguments
 ///
@@ -192,7 +203,6 @@ pub fn mean_f64(array: &Vec<f64>) -> f64 {
 /// # Panics
 ///
 /// This function will panic if the input `number` is less than 2.
-/// This function will panic if no prime factors are found for the input `number`.
 ///
 /// # Examples
 ///
@@ -208,18 +218,10 @@ pub fn factors_prime(number: usize) -> Vec<usize> {
         panic!("eratosthenes::factors_prime cannot work with numbers less than 2.")
     };
 
-    let factors: Vec<usize> = factors(number);
-
-    let result: Vec<usize> = factors
-        .into_iter()
-        .filter(|factor| crate::sequences::is_prime(*factor))
-        .collect();
-
-    if result.is_empty() {
-        panic!("Couldn't populate the vector. Make sure your input is valid.")
-    }
-
-    result
+    factorization::Factorization::new(number as u128)
+        .primes()
+        .map(|prime| prime as usize)
+        .collect()
 }
 
 /// Checks if a given number is a palindrome.
@@ -266,6 +268,7 @@ pub fn is_palindrome(number: usize) -> bool {
 /// let sum = sum_even(&input);
 /// assert_eq!(sum, 12);
 /// ```
+#[allow(clippy::ptr_arg)]
 pub fn sum_even(array: &Vec<usize>) -> usize {
     array.iter().filter(|&item| item % 2 == 0).sum()
 }
@@ -288,10 +291,30 @@ pub fn sum_even(array: &Vec<usize>) -> usize {
 /// let sum = sum_odd(&input);
 /// assert_eq!(sum, 9);
 /// ```
+#[allow(clippy::ptr_arg)]
 pub fn sum_odd(array: &Vec<usize>) -> usize {
     array.iter().filter(|&item| item % 2 == 1).sum()
 }
 
+/// Returns the integer square root of `number`, the largest integer whose square does not
+/// exceed it.
+pub(crate) fn isqrt(number: u128) -> u128 {
+    let mut root: u128 = (number as f64).sqrt() as u128;
+    while root
+        .checked_mul(root)
+        .map_or(true, |square| square > number)
+    {
+        root -= 1;
+    }
+    while (root + 1)
+        .checked_mul(root + 1)
+        .is_some_and(|square| square <= number)
+    {
+        root += 1;
+    }
+    root
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -329,6 +352,7 @@ mod tests {
         assert_eq!(factors(28), vec![1, 2, 4, 7, 14, 28]);
         assert_eq!(factors(1), vec![1]);
         assert_eq!(factors(2), vec![1, 2]);
+        assert_eq!(factors(1_000_000_007 * 1_000_000_009).len(), 4);
     }
 
     #[test]
@@ -337,6 +361,10 @@ mod tests {
         assert_eq!(factors_prime(2), vec![2]);
         assert_eq!(factors_prime(30), vec![2, 3, 5]);
         assert_eq!(factors_prime(99651999), vec![3, 33217333]);
+        assert_eq!(
+            factors_prime(1_000_000_007 * 1_000_000_009),
+            vec![1_000_000_007, 1_000_000_009]
+        );
     }
 
     #[test]
@@ -365,6 +393,15 @@ mod tests {
         assert_eq!(sum_even(&vec![1, 3, 6, 11]), 6)
     }
 
+    #[test]
+    fn isqrt_test() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(u64::MAX as u128), 4_294_967_295);
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+    }
+
     #[test]
     fn sum_odd_test() {
         assert_eq!(sum_odd(&vec![1, 3, 6, 11]), 15)
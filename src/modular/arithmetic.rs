@@ -0,0 +1,571 @@
+use std::fmt;
+
+/// Modular arithmetic on an unsigned integer type, free of overflow for every modulus the type
+/// can hold.
+///
+/// The trait is implemented for `u32`, `u64` and `u128`. Products are taken in the next wider
+/// type for `u32` and `u64`, and for `u128` moduli above 64 bits the 256-bit product is built
+/// from 64-bit halves and divided digit by digit, so no intermediate value overflows. The free
+/// functions of this module, such as [`mod_pow`], call these methods and read better in formulas.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::modular::ModularArithmetic;
+///
+/// assert_eq!(3u32.mod_pow(200, 1_000_000_007), 136_318_165);
+/// assert_eq!(u64::MAX.mod_mul(u64::MAX, 1_000_000_007), 114_944_269);
+/// assert_eq!(3u128.mod_inverse(u128::MAX), None);
+/// ```
+pub trait ModularArithmetic: Copy + Eq + Ord + fmt::Debug + fmt::Display {
+    /// The signed integer type of the same width, holding Bézout coefficients.
+    type Signed: Copy + Eq + Ord + fmt::Debug + fmt::Display;
+
+    /// Returns `(self + other) mod modulus`.
+    fn mod_add(self, other: Self, modulus: Self) -> Self;
+
+    /// Returns `(self - other) mod modulus`, as the non-negative representative.
+    fn mod_sub(self, other: Self, modulus: Self) -> Self;
+
+    /// Returns `(self * other) mod modulus`.
+    fn mod_mul(self, other: Self, modulus: Self) -> Self;
+
+    /// Returns `self^exponent mod modulus`, computed by repeated squaring.
+    fn mod_pow(self, exponent: Self, modulus: Self) -> Self;
+
+    /// Returns the greatest common divisor of `self` and `other`.
+    fn gcd(self, other: Self) -> Self;
+
+    /// Returns `(g, x, y)` where `g` is the greatest common divisor of `self` and `other` and
+    /// `self * x + other * y = g`.
+    fn extended_gcd(self, other: Self) -> (Self, Self::Signed, Self::Signed);
+
+    /// Returns the inverse of `self` modulo `modulus`, or `None` if they are not coprime.
+    fn mod_inverse(self, modulus: Self) -> Option<Self>;
+}
+
+/// Adds two numbers already reduced modulo `modulus` without overflowing.
+macro_rules! add_reduced {
+    ($a:expr, $b:expr, $modulus:expr) => {{
+        let (a, b, modulus) = ($a, $b, $modulus);
+        if a >= modulus - b {
+            a - (modulus - b)
+        } else {
+            a + b
+        }
+    }};
+}
+
+/// Implements the methods of [`ModularArithmetic`] shared by every type, given how to multiply.
+macro_rules! modular_arithmetic {
+    ($unsigned:ty, $signed:ty, $mul:item) => {
+        impl ModularArithmetic for $unsigned {
+            type Signed = $signed;
+
+            fn mod_add(self, other: Self, modulus: Self) -> Self {
+                if modulus == 0 {
+                    zero_modulus("mod_add");
+                }
+                add_reduced!(self % modulus, other % modulus, modulus)
+            }
+
+            fn mod_sub(self, other: Self, modulus: Self) -> Self {
+                if modulus == 0 {
+                    zero_modulus("mod_sub");
+                }
+                let (a, b): (Self, Self) = (self % modulus, other % modulus);
+                if a >= b {
+                    a - b
+                } else {
+                    modulus - (b - a)
+                }
+            }
+
+            $mul
+
+            fn mod_pow(self, mut exponent: Self, modulus: Self) -> Self {
+                if modulus == 0 {
+                    zero_modulus("mod_pow");
+                }
+                let mut base: Self = self % modulus;
+                let mut result: Self = 1 % modulus;
+                while exponent > 0 {
+                    if exponent & 1 == 1 {
+                        result = result.mod_mul(base, modulus);
+                    }
+                    base = base.mod_mul(base, modulus);
+                    exponent >>= 1;
+                }
+                result
+            }
+
+            fn gcd(self, other: Self) -> Self {
+                let (mut a, mut b): (Self, Self) = (self, other);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a
+            }
+
+            fn extended_gcd(self, other: Self) -> (Self, $signed, $signed) {
+                // The returned coefficients are at most `max(self, other) / 2g` in absolute value
+                // and always fit, but the last ones computed, which are discarded, may not. The
+                // coefficients are thus updated with wrapping arithmetic, which is exact modulo
+                // 2^bits and so gives the right values whenever they fit.
+                let (mut old_r, mut r): (Self, Self) = (self, other);
+                let (mut old_x, mut x): ($signed, $signed) = (1, 0);
+                let (mut old_y, mut y): ($signed, $signed) = (0, 1);
+                while r != 0 {
+                    let quotient: Self = old_r / r;
+                    (old_r, r) = (r, old_r - quotient * r);
+                    (old_x, x) = (x, old_x.wrapping_sub((quotient as $signed).wrapping_mul(x)));
+                    (old_y, y) = (y, old_y.wrapping_sub((quotient as $signed).wrapping_mul(y)));
+                }
+                (old_r, old_x, old_y)
+            }
+
+            fn mod_inverse(self, modulus: Self) -> Option<Self> {
+                if modulus == 0 {
+                    zero_modulus("mod_inverse");
+                }
+                let (gcd, x, _) = (self % modulus).extended_gcd(modulus);
+                if gcd != 1 {
+                    return None;
+                }
+
+                let inverse: Self = if x < 0 {
+                    modulus - x.unsigned_abs()
+                } else {
+                    x as Self
+                };
+                Some(inverse % modulus)
+            }
+        }
+    };
+}
+
+modular_arithmetic!(
+    u32,
+    i32,
+    fn mod_mul(self, other: Self, modulus: Self) -> Self {
+        if modulus == 0 {
+            zero_modulus("mod_mul");
+        }
+        (self as u64 * other as u64 % modulus as u64) as u32
+    }
+);
+
+modular_arithmetic!(
+    u64,
+    i64,
+    fn mod_mul(self, other: Self, modulus: Self) -> Self {
+        if modulus == 0 {
+            zero_modulus("mod_mul");
+        }
+        (self as u128 * other as u128 % modulus as u128) as u64
+    }
+);
+
+modular_arithmetic!(
+    u128,
+    i128,
+    fn mod_mul(self, other: Self, modulus: Self) -> Self {
+        if modulus == 0 {
+            zero_modulus("mod_mul");
+        }
+        let (a, b): (Self, Self) = (self % modulus, other % modulus);
+        if modulus <= u64::MAX as u128 {
+            return a * b % modulus;
+        }
+
+        let (high, low) = mul_wide(a, b);
+        reduce_wide(high, low, modulus)
+    }
+);
+
+/// Returns the 256-bit product of two numbers as its high and low halves.
+pub(crate) fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+
+    let (a_high, a_low): (u128, u128) = (a >> 64, a & MASK);
+    let (b_high, b_low): (u128, u128) = (b >> 64, b & MASK);
+
+    let low: u128 = a_low * b_low;
+    let cross_low: u128 = a_low * b_high;
+    let cross_high: u128 = a_high * b_low;
+    let middle: u128 = (low >> 64) + (cross_low & MASK) + (cross_high & MASK);
+
+    let high: u128 = a_high * b_high + (cross_low >> 64) + (cross_high >> 64) + (middle >> 64);
+    (high, (middle << 64) | (low & MASK))
+}
+
+/// Returns `(high * 2^128 + low) mod modulus` for a modulus above 64 bits and `high < modulus`.
+///
+/// This is schoolbook division in base 2^64: the modulus is shifted until its top bit is set,
+/// then each of the two low digits of the number is brought down in turn.
+fn reduce_wide(high: u128, low: u128, modulus: u128) -> u128 {
+    let shift: u32 = modulus.leading_zeros();
+    let divisor: u128 = modulus << shift;
+    let (high, low): (u128, u128) = if shift == 0 {
+        (high, low)
+    } else {
+        ((high << shift) | (low >> (128 - shift)), low << shift)
+    };
+
+    let remainder: u128 = reduce_digit(high, (low >> 64) as u64, divisor);
+    reduce_digit(remainder, low as u64, divisor) >> shift
+}
+
+/// Returns `(remainder * 2^64 + digit) mod divisor` for a divisor with its top bit set and
+/// `remainder < divisor`.
+///
+/// The quotient digit is estimated from the top 128 bits of the number and the top 64 bits of
+/// the divisor, which is never too small and at most 2 too large for such a divisor, so the
+/// difference is corrected by adding the divisor back at most twice.
+fn reduce_digit(remainder: u128, digit: u64, divisor: u128) -> u128 {
+    let divisor_high: u64 = (divisor >> 64) as u64;
+    let quotient: u64 = if (remainder >> 64) as u64 >= divisor_high {
+        u64::MAX
+    } else {
+        (remainder / divisor_high as u128) as u64
+    };
+
+    // The product of the quotient digit and the divisor, as a top digit and 128 low bits.
+    let product_low: u128 = quotient as u128 * (divisor as u64) as u128;
+    let product_high: u128 = quotient as u128 * divisor_high as u128 + (product_low >> 64);
+    let product: u128 = (product_high << 64) | (product_low as u64) as u128;
+
+    let (mut difference, borrow) = ((remainder << 64) | digit as u128).overflowing_sub(product);
+    let mut top: i128 = (remainder >> 64) as i128 - (product_high >> 64) as i128 - borrow as i128;
+    while top < 0 {
+        let (sum, carry) = difference.overflowing_add(divisor);
+        difference = sum;
+        top += carry as i128;
+    }
+    difference
+}
+
+fn zero_modulus(function: &str) -> ! {
+    panic!("eratosthenes::{function} cannot work with a modulus of 0.");
+}
+
+/// Adds two numbers modulo another.
+///
+/// Given two unsigned integers (`a` and `b`) and a modulus (`modulus`), this function returns
+/// `(a + b) mod modulus` without overflowing, for any of `u32`, `u64` and `u128`.
+///
+/// # Arguments
+///
+/// * `a` - The first term.
+/// * `b` - The second term.
+/// * `modulus` - The modulus of the sum.
+///
+/// # Panics
+///
+/// This function will panic if `modulus` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::modular::mod_add;
+///
+/// assert_eq!(mod_add(u64::MAX, u64::MAX, u64::MAX - 1), 2);
+/// ```
+pub fn mod_add<T: ModularArithmetic>(a: T, b: T, modulus: T) -> T {
+    a.mod_add(b, modulus)
+}
+
+/// Subtracts two numbers modulo another.
+///
+/// Given two unsigned integers (`a` and `b`) and a modulus (`modulus`), this function returns
+/// `(a - b) mod modulus` as a number between 0 and `modulus - 1`, for any of `u32`, `u64` and
+/// `u128`.
+///
+/// # Arguments
+///
+/// * `a` - The number subtracted from.
+/// * `b` - The number subtracted.
+/// * `modulus` - The modulus of the difference.
+///
+/// # Panics
+///
+/// This function will panic if `modulus` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::modular::mod_sub;
+///
+/// assert_eq!(mod_sub(3u32, 5, 7), 5);
+/// ```
+pub fn mod_sub<T: ModularArithmetic>(a: T, b: T, modulus: T) -> T {
+    a.mod_sub(b, modulus)
+}
+
+/// Multiplies two numbers modulo another.
+///
+/// Given two unsigned integers (`a` and `b`) and a modulus (`modulus`), this function returns
+/// `(a * b) mod modulus`. The product is taken in `u64` for `u32` and in `u128` for `u64`, so it
+/// never overflows. For `u128` moduli above 64 bits the product is built by doubling and adding.
+///
+/// # Arguments
+///
+/// * `a` - The first factor.
+/// * `b` - The second factor.
+/// * `modulus` - The modulus of the product.
+///
+/// # Panics
+///
+/// This function will panic if `modulus` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::modular::mod_mul;
+///
+/// assert_eq!(mod_mul(u64::MAX, u64::MAX, 1_000_000_007), 114_944_269);
+/// assert_eq!(mod_mul(u128::MAX, 2, u128::MAX - 1), 2);
+/// ```
+pub fn mod_mul<T: ModularArithmetic>(a: T, b: T, modulus: T) -> T {
+    a.mod_mul(b, modulus)
+}
+
+/// Raises a number to a power modulo another.
+///
+/// Given an unsigned integer (`base`), an exponent (`exponent`) and a modulus (`modulus`), this
+/// function returns `base^exponent mod modulus` by repeated squaring, in `O(log exponent)`
+/// multiplications with [`mod_mul`].
+///
+/// # Arguments
+///
+/// * `base` - The number raised to the power.
+/// * `exponent` - The power.
+/// * `modulus` - The modulus of the result.
+///
+/// # Panics
+///
+/// This function will panic if `modulus` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::modular::mod_pow;
+///
+/// // Fermat's little theorem.
+/// assert_eq!(mod_pow(2u64, 1_000_000_006, 1_000_000_007), 1);
+/// assert_eq!(mod_pow(2u128, 127, u128::MAX), 1 << 127);
+/// ```
+pub fn mod_pow<T: ModularArithmetic>(base: T, exponent: T, modulus: T) -> T {
+    base.mod_pow(exponent, modulus)
+}
+
+/// Computes the greatest common divisor of two numbers.
+///
+/// Given two unsigned integers (`a` and `b`), this function returns their greatest common
+/// divisor with the Euclidean algorithm, for any of `u32`, `u64` and `u128`. The greatest common
+/// divisor of 0 and `b` is `b`.
+///
+/// # Arguments
+///
+/// * `a` - The first number.
+/// * `b` - The second number.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::modular::gcd;
+///
+/// assert_eq!(gcd(240u32, 46), 2);
+/// assert_eq!(gcd(0u64, 7), 7);
+/// ```
+pub fn gcd<T: ModularArithmetic>(a: T, b: T) -> T {
+    a.gcd(b)
+}
+
+/// Computes the greatest common divisor of two numbers with Bézout coefficients.
+///
+/// Given two unsigned integers (`a` and `b`), this function returns `(g, x, y)` where `g` is
+/// their greatest common divisor and `x` and `y` are signed integers of the same width with
+/// `a * x + b * y = g`, found with the extended Euclidean algorithm. The coefficients are the
+/// smallest ones, at most `max(a, b) / 2g` in absolute value, so they never overflow.
+///
+/// # Arguments
+///
+/// * `a` - The first number.
+/// * `b` - The second number.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::modular::extended_gcd;
+///
+/// assert_eq!(extended_gcd(240u32, 46), (2, -9, 47));
+/// assert_eq!(extended_gcd(0u64, 0), (0, 1, 0));
+/// ```
+pub fn extended_gcd<T: ModularArithmetic>(a: T, b: T) -> (T, T::Signed, T::Signed) {
+    a.extended_gcd(b)
+}
+
+/// Finds the inverse of a number modulo another.
+///
+/// Given an unsigned integer (`a`) and a modulus (`modulus`), this function returns the number
+/// `x` between 0 and `modulus - 1` with `a * x ≡ 1 (mod modulus)`, or `None` if `a` and the
+/// modulus have a common divisor and no such number exists.
+///
+/// # Arguments
+///
+/// * `a` - The number to invert.
+/// * `modulus` - The modulus of the inverse.
+///
+/// # Panics
+///
+/// This function will panic if `modulus` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::modular::mod_inverse;
+///
+/// assert_eq!(mod_inverse(3u32, 7), Some(5));
+/// assert_eq!(mod_inverse(4u64, 10), None);
+/// ```
+pub fn mod_inverse<T: ModularArithmetic>(a: T, modulus: T) -> Option<T> {
+    a.mod_inverse(modulus)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn mod_add_sub_test() {
+        for modulus in 1..=30u32 {
+            for a in 0..60 {
+                for b in 0..60 {
+                    assert_eq!(mod_add(a, b, modulus), (a + b) % modulus);
+                    assert_eq!(mod_sub(a, b, modulus), (a + 60 * modulus - b) % modulus);
+                }
+            }
+        }
+
+        assert_eq!(mod_add(u32::MAX, u32::MAX, u32::MAX), 0);
+        assert_eq!(
+            mod_add(u128::MAX - 1, u128::MAX - 2, u128::MAX),
+            u128::MAX - 3
+        );
+        assert_eq!(mod_sub(0, u128::MAX - 1, u128::MAX), 1);
+    }
+
+    #[test]
+    fn mod_mul_test() {
+        for modulus in 1..=30u32 {
+            for a in 0..60 {
+                for b in 0..60 {
+                    assert_eq!(mod_mul(a, b, modulus), a * b % modulus);
+                    assert_eq!(
+                        mod_mul(a as u128, b as u128, modulus as u128),
+                        (a * b % modulus) as u128
+                    );
+                }
+            }
+        }
+
+        assert_eq!(mod_mul(u32::MAX, u32::MAX, u32::MAX - 1), 1);
+        assert_eq!(mod_mul(u64::MAX, u64::MAX, u64::MAX - 1), 1);
+        assert_eq!(mod_mul(u128::MAX, u128::MAX, u128::MAX - 1), 1);
+        // (2^64 + 1)^2 = 2^128 + 2^65 + 1, and 2^128 ≡ 1 modulo 2^128 - 1.
+        assert_eq!(
+            mod_mul((1 << 64) + 1, (1 << 64) + 1, u128::MAX),
+            (1 << 65) + 2
+        );
+
+        // Against multiplication by doubling and adding, which cannot overflow.
+        let slow_mul = |a: u128, b: u128, modulus: u128| {
+            (0..128).rev().fold(0, |result: u128, bit| {
+                let doubled: u128 = mod_add(result, result, modulus);
+                if (b >> bit) & 1 == 1 {
+                    mod_add(doubled, a % modulus, modulus)
+                } else {
+                    doubled
+                }
+            })
+        };
+        let mut state: u128 = 0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c834;
+        let mut next = || {
+            state = state
+                .wrapping_mul(0x2360_ed05_1fc6_5da4_4385_df64_9fcc_f645)
+                .wrapping_add(0x5851_f42d_4c95_7f2d_1405_7b7e_f767_814f);
+            state ^ (state >> 67)
+        };
+        for _ in 0..2_000 {
+            let (a, b) = (next(), next());
+            let modulus: u128 = (next() >> (next() % 63)) | (1 << 64);
+            assert_eq!(mod_mul(a, b, modulus), slow_mul(a, b, modulus));
+        }
+        for modulus in [(1 << 64) + 1, (1 << 127) + 1, u128::MAX - 1, u128::MAX] {
+            for (a, b) in [
+                (modulus - 1, modulus - 1),
+                (u128::MAX, u128::MAX),
+                (1 << 64, 1 << 64),
+            ] {
+                assert_eq!(mod_mul(a, b, modulus), slow_mul(a, b, modulus));
+            }
+        }
+    }
+
+    #[test]
+    fn mod_pow_test() {
+        assert_eq!(mod_pow(0u32, 0, 1), 0);
+        assert_eq!(mod_pow(0u32, 0, 7), 1);
+        assert_eq!(mod_pow(5u64, 0, 7), 1);
+        assert_eq!(mod_pow(3u32, 4, 100), 81);
+        assert_eq!(mod_pow(3u32, 5, 100), 43);
+        for prime in [2u64, 3, 1_000_000_007, 18_446_744_073_709_551_557] {
+            assert_eq!(mod_pow(prime - 1, prime - 1, prime), 1);
+        }
+        let mersenne: u128 = (1 << 127) - 1;
+        assert_eq!(mod_pow(3, mersenne - 1, mersenne), 1);
+        assert_eq!(mod_pow(2, 127, mersenne), 1);
+    }
+
+    #[test]
+    fn extended_gcd_test() {
+        for a in 0..100u32 {
+            for b in 0..100u32 {
+                let (gcd, x, y) = extended_gcd(a, b);
+                let divisors: Vec<u32> = (1..=a.max(b))
+                    .filter(|&d| a % d == 0 && b % d == 0)
+                    .collect();
+                assert_eq!(gcd, divisors.last().copied().unwrap_or(0));
+                assert_eq!(super::gcd(a, b), gcd);
+                assert_eq!(a as i32 * x + b as i32 * y, gcd as i32);
+            }
+        }
+
+        let (gcd, x, y) = extended_gcd(u128::MAX, u128::MAX - 1);
+        assert_eq!((gcd, x, y), (1, 1, -1));
+        let (gcd, x, y) = extended_gcd(u64::MAX, 1 << 63);
+        assert_eq!(gcd, 1);
+        assert_eq!(u64::MAX as i128 * x as i128 + (1i128 << 63) * y as i128, 1);
+    }
+
+    #[test]
+    fn mod_inverse_test() {
+        for modulus in 1..=60u32 {
+            for a in 0..120 {
+                let expected: Option<u32> = (0..modulus).find(|&x| a * x % modulus == 1 % modulus);
+                assert_eq!(mod_inverse(a, modulus), expected);
+            }
+        }
+
+        assert_eq!(mod_inverse(2u128, u128::MAX), Some(1 << 127));
+        assert_eq!(mod_inverse(u64::MAX - 1, u64::MAX), Some(u64::MAX - 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn mod_pow_fail() {
+        mod_pow(2u64, 3, 0);
+    }
+}
@@ -0,0 +1,21 @@
+/*!
+This module includes overflow-safe modular arithmetic on unsigned integers.
+*/
+
+pub mod arithmetic;
+pub mod mod_int;
+pub mod montgomery;
+
+pub use self::arithmetic::extended_gcd;
+pub use self::arithmetic::gcd;
+pub use self::arithmetic::mod_add;
+pub use self::arithmetic::mod_inverse;
+pub use self::arithmetic::mod_mul;
+pub use self::arithmetic::mod_pow;
+pub use self::arithmetic::mod_sub;
+pub use self::arithmetic::ModularArithmetic;
+
+pub use self::mod_int::DynamicModInt;
+pub use self::mod_int::ModInt;
+
+pub use self::montgomery::Montgomery;
@@ -0,0 +1,495 @@
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::ModularArithmetic;
+
+/// An integer modulo `M`, a modulus fixed at compile time.
+///
+/// The value is kept reduced between 0 and `M - 1`, and the arithmetic operators work modulo
+/// `M` without overflowing, so formulas read like ordinary arithmetic. Division multiplies by
+/// the modular inverse, and panics if the divisor has none.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::modular::ModInt;
+///
+/// type Mint = ModInt<1_000_000_007>;
+///
+/// // The binomial coefficient C(1000, 500) modulo 10^9 + 7.
+/// let numerator: Mint = (501..=1_000u64).map(Mint::new).product();
+/// let denominator: Mint = (1..=500u64).map(Mint::new).product();
+/// assert_eq!((numerator / denominator).value(), 159_835_829);
+///
+/// assert_eq!(-Mint::new(1), Mint::new(1_000_000_006));
+/// assert_eq!(Mint::new(2).pow(1_000_000_006), Mint::new(1));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ModInt<const M: u64> {
+    value: u64,
+}
+
+impl<const M: u64> ModInt<M> {
+    /// Fails to compile when the modulus is 0.
+    const NONZERO_MODULUS: () = assert!(
+        M > 0,
+        "eratosthenes::ModInt cannot work with a modulus of 0."
+    );
+
+    /// Creates the residue of `value` modulo `M`. A modulus of 0 is rejected at compile time.
+    ///
+    /// ```compile_fail
+    /// use eratosthenes::modular::ModInt;
+    ///
+    /// let value = ModInt::<0>::new(1);
+    /// ```
+    pub fn new(value: u64) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::NONZERO_MODULUS;
+
+        ModInt { value: value % M }
+    }
+
+    /// Returns the modulus `M`.
+    pub fn modulus(&self) -> u64 {
+        M
+    }
+
+    /// Returns the value between 0 and `M - 1`.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Raises the value to the power `exponent` by repeated squaring.
+    pub fn pow(self, exponent: u64) -> Self {
+        ModInt {
+            value: self.value.mod_pow(exponent, M),
+        }
+    }
+
+    /// Returns the inverse of the value, or `None` if it is not coprime to `M`.
+    pub fn inverse(self) -> Option<Self> {
+        let value: u64 = self.value.mod_inverse(M)?;
+        Some(ModInt { value })
+    }
+
+    fn common_modulus(&self, _: &Self) -> u64 {
+        M
+    }
+
+    fn with_value(self, value: u64) -> Self {
+        ModInt { value }
+    }
+}
+
+/// An integer modulo a modulus chosen at runtime.
+///
+/// The value carries its modulus and behaves like [`ModInt`] otherwise. Combining two values
+/// with different moduli panics. As the modulus of an empty sum or product cannot be known,
+/// [`Iterator::sum`] and [`Iterator::product`] panic on an empty iterator, while
+/// [`sum_mod`](DynamicModInt::sum_mod) and [`product_mod`](DynamicModInt::product_mod) are given
+/// the modulus and return 0 and 1 for it.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::modular::DynamicModInt;
+///
+/// let modulus: u64 = "998244353".parse().unwrap();
+/// let two = DynamicModInt::new(2, modulus);
+/// let three = DynamicModInt::new(3, modulus);
+///
+/// assert_eq!((two / three * three).value(), 2);
+/// assert_eq!((two - three).value(), 998_244_352);
+///
+/// let powers: DynamicModInt = (0..10).map(|k| two.pow(k)).sum();
+/// assert_eq!(powers.value(), 1_023);
+/// assert_eq!(DynamicModInt::sum_mod([], modulus).value(), 0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DynamicModInt {
+    value: u64,
+    modulus: u64,
+}
+
+impl DynamicModInt {
+    /// Creates the residue of `value` modulo `modulus`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `modulus` is 0.
+    pub fn new(value: u64, modulus: u64) -> Self {
+        if modulus == 0 {
+            panic!("eratosthenes::DynamicModInt cannot work with a modulus of 0.");
+        }
+
+        DynamicModInt {
+            value: value % modulus,
+            modulus,
+        }
+    }
+
+    /// Returns the modulus.
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// Returns the value between 0 and `modulus - 1`.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Raises the value to the power `exponent` by repeated squaring.
+    pub fn pow(self, exponent: u64) -> Self {
+        self.with_value(self.value.mod_pow(exponent, self.modulus))
+    }
+
+    /// Returns the inverse of the value, or `None` if it is not coprime to the modulus.
+    pub fn inverse(self) -> Option<Self> {
+        let value: u64 = self.value.mod_inverse(self.modulus)?;
+        Some(self.with_value(value))
+    }
+
+    /// Adds the values of an iterator modulo `modulus`, which is 0 for an empty iterator.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `modulus` is 0 or differs from the modulus of a value.
+    pub fn sum_mod(values: impl IntoIterator<Item = Self>, modulus: u64) -> Self {
+        values.into_iter().fold(Self::new(0, modulus), Add::add)
+    }
+
+    /// Multiplies the values of an iterator modulo `modulus`, which is 1 for an empty iterator.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `modulus` is 0 or differs from the modulus of a value.
+    pub fn product_mod(values: impl IntoIterator<Item = Self>, modulus: u64) -> Self {
+        values.into_iter().fold(Self::new(1, modulus), Mul::mul)
+    }
+
+    fn common_modulus(&self, other: &Self) -> u64 {
+        if self.modulus != other.modulus {
+            panic!(
+                "eratosthenes::DynamicModInt cannot combine values modulo {} and {}.",
+                self.modulus, other.modulus
+            );
+        }
+        self.modulus
+    }
+
+    fn with_value(self, value: u64) -> Self {
+        DynamicModInt {
+            value,
+            modulus: self.modulus,
+        }
+    }
+}
+
+/// Implements the arithmetic operators, given `common_modulus` and `with_value` methods.
+macro_rules! arithmetic_operators {
+    ([$($generics:tt)*] $type:ty, $name:literal) => {
+        impl<$($generics)*> Add for $type {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                let modulus: u64 = self.common_modulus(&other);
+                self.with_value(self.value.mod_add(other.value, modulus))
+            }
+        }
+
+        impl<$($generics)*> Sub for $type {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                let modulus: u64 = self.common_modulus(&other);
+                self.with_value(self.value.mod_sub(other.value, modulus))
+            }
+        }
+
+        impl<$($generics)*> Mul for $type {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self {
+                let modulus: u64 = self.common_modulus(&other);
+                self.with_value(self.value.mod_mul(other.value, modulus))
+            }
+        }
+
+        impl<$($generics)*> Div for $type {
+            type Output = Self;
+
+            /// Multiplies by the inverse of `other`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `other` is not coprime to the modulus.
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, other: Self) -> Self {
+                self.common_modulus(&other);
+                match other.inverse() {
+                    Some(inverse) => self * inverse,
+                    None => panic!(
+                        "eratosthenes::{} cannot divide by {}, which has no inverse.",
+                        $name, other.value
+                    ),
+                }
+            }
+        }
+
+        impl<$($generics)*> Neg for $type {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                let modulus: u64 = self.common_modulus(&self);
+                self.with_value(0u64.mod_sub(self.value, modulus))
+            }
+        }
+
+        impl<$($generics)*> AddAssign for $type {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<$($generics)*> SubAssign for $type {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl<$($generics)*> MulAssign for $type {
+            fn mul_assign(&mut self, other: Self) {
+                *self = *self * other;
+            }
+        }
+
+        impl<$($generics)*> DivAssign for $type {
+            fn div_assign(&mut self, other: Self) {
+                *self = *self / other;
+            }
+        }
+
+        impl<$($generics)*> fmt::Display for $type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.value)
+            }
+        }
+    };
+}
+
+arithmetic_operators!([const M: u64] ModInt<M>, "ModInt");
+arithmetic_operators!([] DynamicModInt, "DynamicModInt");
+
+impl<const M: u64> Default for ModInt<M> {
+    /// Returns 0. A modulus of 0 is rejected at compile time, as by [`ModInt::new`].
+    ///
+    /// ```compile_fail
+    /// use eratosthenes::modular::ModInt;
+    ///
+    /// let value = ModInt::<0>::default();
+    /// ```
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<const M: u64> Sum for ModInt<M> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(0), Add::add)
+    }
+}
+
+impl<'a, const M: u64> Sum<&'a Self> for ModInt<M> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<const M: u64> Product for ModInt<M> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(1), Mul::mul)
+    }
+}
+
+impl<'a, const M: u64> Product<&'a Self> for ModInt<M> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl Sum for DynamicModInt {
+    /// Adds the values of a non-empty iterator.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the iterator is empty, or the moduli of the values differ.
+    /// [`DynamicModInt::sum_mod`] handles empty iterators.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(Add::add)
+            .expect("eratosthenes::DynamicModInt cannot sum an empty iterator.")
+    }
+}
+
+impl<'a> Sum<&'a Self> for DynamicModInt {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product for DynamicModInt {
+    /// Multiplies the values of a non-empty iterator.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the iterator is empty, or the moduli of the values differ.
+    /// [`DynamicModInt::product_mod`] handles empty iterators.
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(Mul::mul)
+            .expect("eratosthenes::DynamicModInt cannot multiply an empty iterator.")
+    }
+}
+
+impl<'a> Product<&'a Self> for DynamicModInt {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+/// Implements `From` an integer type for [`ModInt`], reducing negative values to their
+/// non-negative residue.
+macro_rules! mod_int_from {
+    ($($type:ty),*) => {
+        $(
+            impl<const M: u64> From<$type> for ModInt<M> {
+                fn from(value: $type) -> Self {
+                    let residue: u64 = (value as i128).rem_euclid(M as i128) as u64;
+                    Self::new(residue)
+                }
+            }
+        )*
+    };
+}
+
+mod_int_from!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    type Mint = ModInt<1_000_000_007>;
+
+    #[test]
+    fn mod_int_test() {
+        let a: Mint = Mint::new(1_000_000_000);
+        let b: Mint = Mint::new(10);
+        assert_eq!(Mint::new(1_000_000_007), Mint::new(0));
+        assert_eq!(Mint::default().value(), 0);
+        assert_eq!(a.modulus(), 1_000_000_007);
+        assert_eq!((a + b).value(), 3);
+        assert_eq!((b - a).value(), 17);
+        assert_eq!((a * a).value(), 49);
+        assert_eq!((a / b * b), a);
+        assert_eq!(-Mint::new(0), Mint::new(0));
+        assert_eq!((-b).value(), 999_999_997);
+        assert_eq!(b.pow(0), Mint::new(1));
+        assert_eq!(b.pow(9), a);
+        assert_eq!(Mint::new(0).inverse(), None);
+        assert_eq!(b.inverse().map(|inverse| inverse * b), Some(Mint::new(1)));
+
+        let mut c: Mint = a;
+        c += b;
+        c -= a;
+        c *= b;
+        c /= Mint::new(4);
+        assert_eq!(c * Mint::new(2), Mint::new(50));
+
+        assert_eq!(Mint::from(-1i32), Mint::new(1_000_000_006));
+        assert_eq!(Mint::from(u64::MAX).value(), u64::MAX % 1_000_000_007);
+        assert_eq!(Mint::from(i64::MIN).value(), 708_828_003);
+        assert_eq!(a.to_string(), "1000000000");
+
+        assert_eq!(ModInt::<1>::new(5).value(), 0);
+        assert_eq!(
+            (ModInt::<{ u64::MAX }>::new(u64::MAX - 1) * ModInt::new(2)).value(),
+            u64::MAX - 2
+        );
+    }
+
+    #[test]
+    fn mod_int_sum_product_test() {
+        let values: Vec<Mint> = (1..=100u64).map(Mint::new).collect();
+        assert_eq!(values.iter().sum::<Mint>(), Mint::new(5_050));
+        assert_eq!(values.iter().copied().sum::<Mint>(), Mint::new(5_050));
+        // 100! modulo 10^9 + 7.
+        assert_eq!(values.iter().product::<Mint>(), Mint::new(437_918_130));
+        assert_eq!(Vec::<Mint>::new().into_iter().sum::<Mint>(), Mint::new(0));
+        assert_eq!(
+            Vec::<Mint>::new().into_iter().product::<Mint>(),
+            Mint::new(1)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn mod_int_fail() {
+        let _ = Mint::new(1) / Mint::new(0);
+    }
+
+    #[test]
+    fn dynamic_mod_int_test() {
+        let a: DynamicModInt = DynamicModInt::new(1_000_000_000, 1_000_000_007);
+        let b: DynamicModInt = DynamicModInt::new(10, 1_000_000_007);
+        assert_eq!(a.modulus(), 1_000_000_007);
+        assert_eq!((a + b).value(), 3);
+        assert_eq!((b - a).value(), 17);
+        assert_eq!((a * a).value(), 49);
+        assert_eq!(a / b * b, a);
+        assert_eq!((-b).value(), 999_999_997);
+        assert_eq!(b.pow(9), a);
+        assert_eq!(DynamicModInt::new(6, 9).inverse(), None);
+        assert_eq!(b.to_string(), "10");
+
+        let values: Vec<DynamicModInt> = (1..=100u64)
+            .map(|value| DynamicModInt::new(value, 1_000_000_007))
+            .collect();
+        let sum: DynamicModInt = DynamicModInt::sum_mod(values.iter().copied(), 1_000_000_007);
+        assert_eq!(sum.value(), 5_050);
+        let product: DynamicModInt =
+            DynamicModInt::product_mod(values.iter().copied(), 1_000_000_007);
+        assert_eq!(product.value(), 437_918_130);
+        assert_eq!(values.iter().sum::<DynamicModInt>(), sum);
+        assert_eq!(values.iter().product::<DynamicModInt>(), product);
+        assert_eq!(DynamicModInt::sum_mod([], 7), DynamicModInt::new(0, 7));
+        assert_eq!(DynamicModInt::product_mod([], 7), DynamicModInt::new(1, 7));
+
+        for value in 0..(1u64 << 10) {
+            let static_value: ModInt<998_244_353> = ModInt::new(value * 123_456_789);
+            let dynamic_value: DynamicModInt = DynamicModInt::new(value * 123_456_789, 998_244_353);
+            assert_eq!(
+                static_value.pow(value).value(),
+                dynamic_value.pow(value).value()
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn dynamic_mod_int_fail() {
+        let _ = DynamicModInt::new(1, 7) + DynamicModInt::new(1, 11);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dynamic_mod_int_sum_fail() {
+        DynamicModInt::sum_mod([DynamicModInt::new(1, 7)], 11);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dynamic_mod_int_empty_sum_fail() {
+        let _: DynamicModInt = std::iter::empty::<DynamicModInt>().sum();
+    }
+}
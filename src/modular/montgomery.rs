@@ -0,0 +1,299 @@
+use super::arithmetic::mul_wide;
+use super::mod_mul;
+
+/// A Montgomery arithmetic context for an odd 64-bit modulus.
+///
+/// A number `a` is represented by `a * R mod m` with `R = 2^64`. In this form a product is
+/// reduced with two 64-bit multiplications and a subtraction instead of a 128-bit division, which
+/// makes long chains of multiplications, as in modular exponentiation, about twice as fast as
+/// [`mod_mul`](super::mod_mul) on x86-64 (see `cargo bench`).
+///
+/// Values in Montgomery form are plain `u64` numbers between 0 and `m - 1`: convert in with
+/// [`to_montgomery`](Montgomery::to_montgomery), compute with the methods of the context, and
+/// convert back with [`from_montgomery`](Montgomery::from_montgomery). Addition, subtraction and
+/// equality work on the representations unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::modular::{mod_pow, Montgomery};
+///
+/// let modulus: u64 = 18_446_744_073_709_551_557;
+/// let montgomery = Montgomery::new(modulus);
+///
+/// let base: u64 = montgomery.to_montgomery(3);
+/// let power: u64 = montgomery.pow(base, modulus - 2);
+/// assert_eq!(montgomery.from_montgomery(power), mod_pow(3, modulus - 2, modulus));
+///
+/// let product: u64 = montgomery.mul(base, power);
+/// assert_eq!(product, montgomery.one());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Montgomery {
+    modulus: u64,
+    /// The inverse of the modulus modulo 2^64.
+    inverse: u64,
+    /// `R^2 mod m`, which brings a number into Montgomery form in a single product.
+    r_squared: u64,
+    /// `R mod m`, the Montgomery form of 1.
+    one: u64,
+}
+
+impl Montgomery {
+    /// Creates the context for an odd modulus.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the input `modulus` is even.
+    pub fn new(modulus: u64) -> Self {
+        if modulus % 2 == 0 {
+            panic!("eratosthenes::Montgomery can only work with an odd modulus.");
+        }
+
+        // Every odd number is its own inverse modulo 8, and each Newton step doubles the number
+        // of correct low bits: 3, 6, 12, 24, 48, then 96 >= 64.
+        let mut inverse: u64 = modulus;
+        for _ in 0..5 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(modulus.wrapping_mul(inverse)));
+        }
+
+        let one: u64 = ((1u128 << 64) % modulus as u128) as u64;
+        let r_squared: u64 = (one as u128 * one as u128 % modulus as u128) as u64;
+
+        Montgomery {
+            modulus,
+            inverse,
+            r_squared,
+            one,
+        }
+    }
+
+    /// Returns the modulus.
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// Returns the Montgomery form of 1.
+    pub fn one(&self) -> u64 {
+        self.one
+    }
+
+    /// Converts a number into Montgomery form.
+    pub fn to_montgomery(&self, value: u64) -> u64 {
+        self.mul(value % self.modulus, self.r_squared)
+    }
+
+    /// Converts a number out of Montgomery form.
+    pub fn from_montgomery(&self, value: u64) -> u64 {
+        self.reduce(value as u128)
+    }
+
+    /// Adds two numbers in Montgomery form.
+    pub fn add(&self, a: u64, b: u64) -> u64 {
+        let (sum, overflow) = a.overflowing_add(b);
+        if overflow || sum >= self.modulus {
+            sum.wrapping_sub(self.modulus)
+        } else {
+            sum
+        }
+    }
+
+    /// Subtracts two numbers in Montgomery form.
+    pub fn sub(&self, a: u64, b: u64) -> u64 {
+        let (difference, borrow) = a.overflowing_sub(b);
+        if borrow {
+            difference.wrapping_add(self.modulus)
+        } else {
+            difference
+        }
+    }
+
+    /// Multiplies two numbers in Montgomery form.
+    pub fn mul(&self, a: u64, b: u64) -> u64 {
+        self.reduce(a as u128 * b as u128)
+    }
+
+    /// Raises a number in Montgomery form to the power `exponent` by repeated squaring.
+    pub fn pow(&self, mut base: u64, mut exponent: u64) -> u64 {
+        let mut result: u64 = self.one;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Returns `value / R mod m` for `value < m * R`.
+    ///
+    /// The multiple of the modulus `q * m` agreeing with `value` on the low 64 bits is
+    /// subtracted, so the difference divided by `R` is just the difference of the high halves.
+    fn reduce(&self, value: u128) -> u64 {
+        let quotient: u64 = (value as u64).wrapping_mul(self.inverse);
+        let multiple: u64 = ((quotient as u128 * self.modulus as u128) >> 64) as u64;
+
+        let (difference, borrow) = ((value >> 64) as u64).overflowing_sub(multiple);
+        if borrow {
+            difference.wrapping_add(self.modulus)
+        } else {
+            difference
+        }
+    }
+}
+
+/// A Montgomery arithmetic context for an odd 128-bit modulus, with `R = 2^128`.
+///
+/// It works like [`Montgomery`], with the 256-bit products built from 64-bit halves. Only the
+/// operations needed by Pollard's rho on moduli above 64 bits are provided.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct Montgomery128 {
+    modulus: u128,
+    /// The inverse of the modulus modulo 2^128.
+    inverse: u128,
+    /// `R^2 mod m`, which brings a number into Montgomery form in a single product.
+    r_squared: u128,
+}
+
+impl Montgomery128 {
+    /// Creates the context for an odd modulus.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the input `modulus` is even.
+    pub(crate) fn new(modulus: u128) -> Self {
+        if modulus % 2 == 0 {
+            panic!("eratosthenes::Montgomery128 can only work with an odd modulus.");
+        }
+
+        // Six Newton steps from 3 correct low bits reach 192 >= 128.
+        let mut inverse: u128 = modulus;
+        for _ in 0..6 {
+            inverse = inverse.wrapping_mul(2u128.wrapping_sub(modulus.wrapping_mul(inverse)));
+        }
+
+        let one: u128 = 0u128.wrapping_sub(modulus) % modulus;
+        Montgomery128 {
+            modulus,
+            inverse,
+            r_squared: mod_mul(one, one, modulus),
+        }
+    }
+
+    /// Converts a number into Montgomery form.
+    pub(crate) fn to_montgomery(self, value: u128) -> u128 {
+        self.mul(value % self.modulus, self.r_squared)
+    }
+
+    /// Adds two numbers in Montgomery form.
+    pub(crate) fn add(self, a: u128, b: u128) -> u128 {
+        let (sum, overflow) = a.overflowing_add(b);
+        if overflow || sum >= self.modulus {
+            sum.wrapping_sub(self.modulus)
+        } else {
+            sum
+        }
+    }
+
+    /// Multiplies two numbers in Montgomery form.
+    pub(crate) fn mul(self, a: u128, b: u128) -> u128 {
+        let (high, low) = mul_wide(a, b);
+        let quotient: u128 = low.wrapping_mul(self.inverse);
+        let (multiple, _) = mul_wide(quotient, self.modulus);
+
+        let (difference, borrow) = high.overflowing_sub(multiple);
+        if borrow {
+            difference.wrapping_add(self.modulus)
+        } else {
+            difference
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::modular::{mod_add, mod_mul, mod_pow, mod_sub};
+
+    #[test]
+    fn montgomery_test() {
+        for modulus in [
+            1,
+            3,
+            5,
+            7,
+            1_000_000_007,
+            (1 << 63) + 1,
+            u64::MAX - 2,
+            u64::MAX,
+        ] {
+            let montgomery: Montgomery = Montgomery::new(modulus);
+            assert_eq!(montgomery.modulus(), modulus);
+            assert_eq!(montgomery.from_montgomery(montgomery.one()), 1 % modulus);
+
+            for (a, b) in [
+                (0, 0),
+                (1, 1),
+                (2, modulus - 1),
+                (modulus - 1, modulus - 1),
+                (123_456_789, 987_654_321),
+                (u64::MAX, u64::MAX / 3),
+            ] {
+                let (a_form, b_form) = (montgomery.to_montgomery(a), montgomery.to_montgomery(b));
+                assert_eq!(montgomery.from_montgomery(a_form), a % modulus);
+                assert_eq!(
+                    montgomery.from_montgomery(montgomery.mul(a_form, b_form)),
+                    mod_mul(a, b, modulus)
+                );
+                assert_eq!(
+                    montgomery.from_montgomery(montgomery.add(a_form, b_form)),
+                    mod_add(a, b, modulus)
+                );
+                assert_eq!(
+                    montgomery.from_montgomery(montgomery.sub(a_form, b_form)),
+                    mod_sub(a, b, modulus)
+                );
+                assert_eq!(
+                    montgomery.from_montgomery(montgomery.pow(a_form, b)),
+                    mod_pow(a, b, modulus)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn montgomery128_test() {
+        for modulus in [
+            3,
+            1_000_000_007,
+            (1 << 64) + 13,
+            (1 << 127) - 1,
+            u128::MAX - 2,
+            u128::MAX,
+        ] {
+            let montgomery: Montgomery128 = Montgomery128::new(modulus);
+            let back = |value: u128| montgomery.mul(value, 1);
+
+            for (a, b) in [
+                (0, 0),
+                (1, 1),
+                (2, modulus - 1),
+                (modulus - 1, modulus - 1),
+                (u128::MAX, u128::MAX / 3),
+            ] {
+                let (a_form, b_form) = (montgomery.to_montgomery(a), montgomery.to_montgomery(b));
+                assert_eq!(back(a_form), a % modulus);
+                assert_eq!(back(montgomery.mul(a_form, b_form)), mod_mul(a, b, modulus));
+                assert_eq!(back(montgomery.add(a_form, b_form)), mod_add(a, b, modulus));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn montgomery_fail() {
+        Montgomery::new(1 << 32);
+    }
+}
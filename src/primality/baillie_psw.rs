@@ -0,0 +1,222 @@
+use crate::modular::{mod_add, mod_mul, mod_pow, mod_sub};
+
+/// Small primes used to discard most composites before the Baillie–PSW rounds.
+const SMALL_PRIMES: [u128; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// Checks if a 128-bit number is prime using the Baillie–PSW test.
+///
+/// Given an unsigned integer (`number`), this function combines a strong probable prime test to
+/// base 2 with a strong Lucas probable prime test using Selfridge's parameters. Numbers that fit
+/// in a `u64` are handed to the deterministic [`miller_rabin`](super::miller_rabin) test, so the
+/// result is exact there. Above 64 bits no composite passing Baillie–PSW is known, but none has
+/// been ruled out either, so the answer is a probable prime.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned 128-bit integer. The number to check for primality.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::primality::baillie_psw;
+///
+/// let mersenne = (1u128 << 127) - 1;
+/// assert!(baillie_psw(mersenne));
+/// assert!(!baillie_psw(mersenne - 2));
+/// ```
+pub fn baillie_psw(number: u128) -> bool {
+    if number <= u64::MAX as u128 {
+        return super::miller_rabin(number as u64);
+    }
+
+    for &prime in SMALL_PRIMES.iter() {
+        if number % prime == 0 {
+            return false;
+        }
+    }
+
+    strong_probable_prime(number, 2) && strong_lucas_probable_prime(number)
+}
+
+/// Runs the strong probable prime test on the odd `number` with the given `base`.
+fn strong_probable_prime(number: u128, base: u128) -> bool {
+    let trailing_zeros: u32 = (number - 1).trailing_zeros();
+    let odd_part: u128 = (number - 1) >> trailing_zeros;
+
+    let mut x: u128 = mod_pow(base % number, odd_part, number);
+    if x == 1 || x == number - 1 {
+        return true;
+    }
+
+    for _ in 1..trailing_zeros {
+        x = mod_mul(x, x, number);
+        if x == number - 1 {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Runs the strong Lucas probable prime test on the odd `number`, with `P = 1` and `D` the first
+/// of 5, -7, 9, -11, ... whose Jacobi symbol modulo `number` is -1.
+fn strong_lucas_probable_prime(number: u128) -> bool {
+    let root: u128 = crate::isqrt(number);
+    if root * root == number {
+        return false;
+    }
+
+    // Selfridge's method A. The magnitude of D grows by 2 at each step and flips sign.
+    let mut magnitude: u128 = 5;
+    let mut negative: bool = false;
+    let d: u128 = loop {
+        let d: u128 = if negative {
+            number - magnitude % number
+        } else {
+            magnitude % number
+        };
+        match jacobi(d, number) {
+            -1 => break d,
+            0 if magnitude % number != 0 => return false,
+            _ => {}
+        }
+        magnitude += 2;
+        negative = !negative;
+    };
+
+    // Q = (1 - D) / 4, reduced modulo `number`.
+    let q: u128 = if negative {
+        (1 + magnitude) / 4 % number
+    } else {
+        number - (magnitude - 1) / 4 % number
+    };
+
+    let trailing_zeros: u32 = (number + 1).trailing_zeros();
+    let odd_part: u128 = (number + 1) >> trailing_zeros;
+
+    let mut u: u128 = 1;
+    let mut v: u128 = 1;
+    let mut q_power: u128 = q;
+    for bit in (0..127 - odd_part.leading_zeros()).rev() {
+        u = mod_mul(u, v, number);
+        v = mod_sub(
+            mod_mul(v, v, number),
+            mod_add(q_power, q_power, number),
+            number,
+        );
+        q_power = mod_mul(q_power, q_power, number);
+
+        if (odd_part >> bit) & 1 == 1 {
+            let next_u: u128 = half_mod(mod_add(u, v, number), number);
+            let next_v: u128 = half_mod(mod_add(mod_mul(d, u, number), v, number), number);
+            u = next_u;
+            v = next_v;
+            q_power = mod_mul(q_power, q, number);
+        }
+    }
+
+    if u == 0 || v == 0 {
+        return true;
+    }
+
+    for _ in 1..trailing_zeros {
+        v = mod_sub(
+            mod_mul(v, v, number),
+            mod_add(q_power, q_power, number),
+            number,
+        );
+        if v == 0 {
+            return true;
+        }
+        q_power = mod_mul(q_power, q_power, number);
+    }
+
+    false
+}
+
+/// Computes the Jacobi symbol `(a / n)` for an odd `n`.
+fn jacobi(mut a: u128, mut n: u128) -> i32 {
+    let mut result: i32 = 1;
+    a %= n;
+    while a != 0 {
+        while a % 2 == 0 {
+            a /= 2;
+            if n % 8 == 3 || n % 8 == 5 {
+                result = -result;
+            }
+        }
+        std::mem::swap(&mut a, &mut n);
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a %= n;
+    }
+
+    if n == 1 {
+        result
+    } else {
+        0
+    }
+}
+
+/// Divides `a` by 2 modulo the odd `modulus` without overflowing.
+fn half_mod(a: u128, modulus: u128) -> u128 {
+    if a % 2 == 0 {
+        a / 2
+    } else {
+        a / 2 + modulus / 2 + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn baillie_psw_test() {
+        assert!(!baillie_psw(0));
+        assert!(!baillie_psw(1));
+        assert!(baillie_psw(2));
+        assert!(baillie_psw((1 << 89) - 1));
+        assert!(baillie_psw((1 << 127) - 1));
+        assert!(baillie_psw(1_000_000_000_000_000_000_000_000_000_057));
+        assert!(baillie_psw(
+            340_282_366_920_938_463_463_374_607_431_768_211_297
+        ));
+        assert!(!baillie_psw((1 << 67) - 1));
+        assert!(!baillie_psw(((1 << 61) - 1) * ((1 << 61) - 1)));
+        assert!(!baillie_psw(((1 << 61) - 1) * 1_000_000_000_000_000_003));
+        assert!(!baillie_psw(u128::MAX));
+
+        let offsets: Vec<u128> = (0..1000)
+            .filter(|&offset| baillie_psw((1 << 64) + offset))
+            .collect();
+        assert_eq!(
+            offsets,
+            vec![
+                13, 37, 51, 81, 93, 141, 307, 331, 393, 493, 541, 597, 637, 651, 717, 741, 745,
+                757, 805, 807, 885, 925, 961, 981, 997
+            ]
+        );
+    }
+
+    #[test]
+    fn strong_lucas_probable_prime_test() {
+        let primes = crate::sequences::primes(100_000);
+        let tested: Vec<usize> = (3..=100_000)
+            .step_by(2)
+            .filter(|&number| {
+                strong_probable_prime(number as u128, 2)
+                    && strong_lucas_probable_prime(number as u128)
+            })
+            .collect();
+        assert_eq!(tested, primes[1..]);
+
+        assert!(strong_lucas_probable_prime(5_459));
+        assert!(strong_lucas_probable_prime(5_777));
+        assert!(!strong_probable_prime(5_777, 2));
+    }
+}
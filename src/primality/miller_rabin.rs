@@ -0,0 +1,94 @@
+use crate::modular::Montgomery;
+
+/// Bases for which the strong probable prime test is deterministic for every 64-bit integer.
+/// The first twelve primes are sufficient up to 3.18 * 10^23.
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Checks if a 64-bit number is prime using the deterministic Miller–Rabin test.
+///
+/// Given an unsigned integer (`number`), this function runs the strong probable prime test
+/// against the first twelve prime bases, which is known to never accept a composite below
+/// 3.18 * 10^23, so the result is exact for every `u64`. The products are computed in Montgomery
+/// form, so there is neither a 128-bit division nor an overflow, even near `u64::MAX`.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned 64-bit integer. The number to check for primality.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::primality::miller_rabin;
+///
+/// assert!(miller_rabin(1_000_000_000_000_000_003));
+/// assert!(!miller_rabin(1_000_000_000_000_000_001));
+/// ```
+pub fn miller_rabin(number: u64) -> bool {
+    if number < 2 {
+        return false;
+    }
+
+    for &witness in WITNESSES.iter() {
+        if number % witness == 0 {
+            return number == witness;
+        }
+    }
+
+    let montgomery: Montgomery = Montgomery::new(number);
+    WITNESSES
+        .iter()
+        .all(|&witness| strong_probable_prime(&montgomery, witness))
+}
+
+/// Runs a single round of the strong probable prime test on the odd modulus of `montgomery`
+/// with the given `base`, returning `false` when `base` proves the modulus composite.
+fn strong_probable_prime(montgomery: &Montgomery, base: u64) -> bool {
+    let number: u64 = montgomery.modulus();
+    let trailing_zeros: u32 = (number - 1).trailing_zeros();
+    let odd_part: u64 = (number - 1) >> trailing_zeros;
+
+    let one: u64 = montgomery.one();
+    let minus_one: u64 = montgomery.sub(0, one);
+    let mut x: u64 = montgomery.pow(montgomery.to_montgomery(base), odd_part);
+    if x == one || x == minus_one {
+        return true;
+    }
+
+    for _ in 1..trailing_zeros {
+        x = montgomery.mul(x, x);
+        if x == minus_one {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn miller_rabin_test() {
+        assert!(!miller_rabin(0));
+        assert!(!miller_rabin(1));
+        assert!(miller_rabin(2));
+        assert!(miller_rabin(37));
+        assert!(!miller_rabin(561));
+        assert!(!miller_rabin(3_215_031_751));
+        assert!(!miller_rabin(3_825_123_056_546_413_051));
+        assert!(miller_rabin(999_999_999_999_999_989));
+        assert!(miller_rabin(18_446_744_073_709_551_557));
+        assert!(!miller_rabin(u64::MAX));
+    }
+
+    #[test]
+    fn miller_rabin_agrees_with_sieve_test() {
+        let primes = crate::sequences::primes(100_000);
+        let tested: Vec<usize> = (0..=100_000)
+            .filter(|&number| miller_rabin(number as u64))
+            .collect();
+        assert_eq!(tested, primes);
+    }
+}
@@ -0,0 +1,13 @@
+/*!
+This module includes primality tests for integers too large for trial division.
+*/
+
+pub mod baillie_psw;
+pub mod miller_rabin;
+pub mod pratt;
+
+pub use self::baillie_psw::baillie_psw;
+pub use self::miller_rabin::miller_rabin;
+
+pub use self::pratt::ParsePrattCertificateError;
+pub use self::pratt::PrattCertificate;
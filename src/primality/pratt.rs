@@ -0,0 +1,387 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::modular::mod_pow;
+
+/// Deepest nesting of certificates accepted when parsing or verifying. Every prime factor `q` of
+/// `p - 1` is at most `p / 2` except for `p = 3`, so a certificate of a `u128` is never deeper
+/// than 128, and anything deeper is rejected before it can exhaust the stack.
+const MAX_DEPTH: usize = 128;
+
+/// Number of candidate witnesses tried before giving up. The least witness of a prime is tiny
+/// in practice, while a composite that passed the probable prime test has none at all.
+const WITNESS_SEARCH_LIMIT: u128 = 1 << 16;
+
+/// A Pratt certificate, a proof that a number is prime which can be checked without trusting
+/// the primality test that found it.
+///
+/// A number `p` is prime exactly when some witness `a` has multiplicative order `p - 1` modulo
+/// `p`, that is `a^(p - 1) ≡ 1` and `a^((p - 1) / q) ≢ 1 (mod p)` for every prime `q` dividing
+/// `p - 1`. The certificate holds the witness and, recursively, a certificate for each of those
+/// primes `q`, ending at 2, for which `p - 1 = 1` has no prime factor.
+///
+/// Certificates are written as `p(a; q1, q2, ...)` where each `qi` is itself a certificate, and a
+/// leaf is written as its prime alone.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::primality::PrattCertificate;
+///
+/// let certificate = PrattCertificate::new(7).unwrap();
+/// assert_eq!(certificate.to_string(), "7(3; 2, 3(2; 2))");
+/// assert!(certificate.verify());
+///
+/// let parsed: PrattCertificate = "7(3; 2, 3(2; 2))".parse().unwrap();
+/// assert_eq!(parsed, certificate);
+///
+/// // A witness of order 3 instead of 6 does not prove anything.
+/// let forged: PrattCertificate = "7(2; 2, 3(2; 2))".parse().unwrap();
+/// assert!(!forged.verify());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PrattCertificate {
+    prime: u128,
+    witness: u128,
+    factors: Vec<PrattCertificate>,
+}
+
+impl PrattCertificate {
+    /// Builds the certificate of a prime, or returns `None` if the number is not prime.
+    ///
+    /// The number is first checked with [`baillie_psw`](super::baillie_psw), then `p - 1` is
+    /// factorized with [`prime_factors`](crate::factorization::prime_factors) and the smallest
+    /// witness is searched from 2 upwards. The factors of `p - 1` are certified recursively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eratosthenes::primality::PrattCertificate;
+    ///
+    /// let certificate = PrattCertificate::new(1_000_000_007).unwrap();
+    /// assert_eq!(certificate.witness(), 5);
+    /// assert!(certificate.verify());
+    ///
+    /// assert_eq!(PrattCertificate::new(1_000_000_009 * 3), None);
+    /// ```
+    pub fn new(number: u128) -> Option<Self> {
+        if !super::baillie_psw(number) {
+            return None;
+        }
+
+        Self::certify(number)
+    }
+
+    /// Certifies a number already believed to be prime.
+    fn certify(prime: u128) -> Option<Self> {
+        if prime == 2 {
+            return Some(Self::leaf());
+        }
+
+        let mut primes: Vec<u128> = crate::factorization::prime_factors(prime - 1);
+        primes.dedup();
+
+        // Only a prime has a witness of full order, so a composite that passed the probable prime
+        // test is rejected here once the search gives up.
+        let witness: u128 = (2..prime.min(WITNESS_SEARCH_LIMIT)).find(|&witness| {
+            mod_pow(witness, prime - 1, prime) == 1
+                && primes
+                    .iter()
+                    .all(|&factor| mod_pow(witness, (prime - 1) / factor, prime) != 1)
+        })?;
+
+        let factors: Vec<PrattCertificate> = primes
+            .into_iter()
+            .map(Self::certify)
+            .collect::<Option<Vec<PrattCertificate>>>()?;
+
+        Some(PrattCertificate {
+            prime,
+            witness,
+            factors,
+        })
+    }
+
+    /// The certificate of 2, which needs no witness besides 1.
+    fn leaf() -> Self {
+        PrattCertificate {
+            prime: 2,
+            witness: 1,
+            factors: Vec::new(),
+        }
+    }
+
+    /// Returns the prime proven by the certificate.
+    pub fn prime(&self) -> u128 {
+        self.prime
+    }
+
+    /// Returns the witness of order `p - 1` modulo the prime.
+    pub fn witness(&self) -> u128 {
+        self.witness
+    }
+
+    /// Returns the certificates of the distinct prime factors of `p - 1`.
+    pub fn factors(&self) -> &[PrattCertificate] {
+        &self.factors
+    }
+
+    /// Checks the certificate, returning `true` if it proves that its number is prime.
+    ///
+    /// Only modular exponentiation and division are used: the witness must have order `p - 1`
+    /// with respect to every listed factor, the listed factors must account for all of `p - 1`,
+    /// and each of them must carry a valid certificate of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eratosthenes::primality::PrattCertificate;
+    ///
+    /// // 2 is missing from the factors of 9 - 1.
+    /// let forged: PrattCertificate = "9(2; 3(2; 2))".parse().unwrap();
+    /// assert!(!forged.verify());
+    /// ```
+    pub fn verify(&self) -> bool {
+        self.verify_at(0)
+    }
+
+    fn verify_at(&self, depth: usize) -> bool {
+        let prime: u128 = self.prime;
+        if depth > MAX_DEPTH {
+            return false;
+        }
+        if prime < 2 || mod_pow(self.witness, prime - 1, prime) != 1 {
+            return false;
+        }
+
+        let mut remaining: u128 = prime - 1;
+        for certificate in &self.factors {
+            let factor: u128 = certificate.prime;
+            if factor < 2 || remaining % factor != 0 {
+                return false;
+            }
+            while remaining % factor == 0 {
+                remaining /= factor;
+            }
+
+            if mod_pow(self.witness, (prime - 1) / factor, prime) == 1
+                || !certificate.verify_at(depth + 1)
+            {
+                return false;
+            }
+        }
+
+        remaining == 1
+    }
+}
+
+impl fmt::Display for PrattCertificate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.prime)?;
+        if self.factors.is_empty() {
+            return Ok(());
+        }
+
+        write!(f, "({};", self.witness)?;
+        for (index, factor) in self.factors.iter().enumerate() {
+            let separator: &str = if index == 0 { " " } else { ", " };
+            write!(f, "{separator}{factor}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl FromStr for PrattCertificate {
+    type Err = ParsePrattCertificateError;
+
+    /// Parses a certificate written as by its [`Display`](fmt::Display) implementation. The
+    /// certificate is only read, call [`verify`](PrattCertificate::verify) to check it. Nesting
+    /// deeper than any certificate of a `u128` is rejected.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut parser: Parser = Parser { text, position: 0 };
+        let certificate: PrattCertificate = parser.certificate(0)?;
+        parser.skip_whitespace();
+
+        if parser.position != text.len() {
+            return Err(parser.error());
+        }
+        Ok(certificate)
+    }
+}
+
+/// The error returned when parsing a [`PrattCertificate`] fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParsePrattCertificateError {
+    position: usize,
+}
+
+impl ParsePrattCertificateError {
+    /// Returns the byte offset in the text at which parsing failed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParsePrattCertificateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid Pratt certificate at byte {}", self.position)
+    }
+}
+
+impl Error for ParsePrattCertificateError {}
+
+/// A recursive descent parser over the text of a certificate.
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn certificate(
+        &mut self,
+        depth: usize,
+    ) -> Result<PrattCertificate, ParsePrattCertificateError> {
+        self.skip_whitespace();
+        if depth > MAX_DEPTH {
+            return Err(self.error());
+        }
+
+        let prime: u128 = self.number()?;
+        if !self.eat('(') {
+            return Ok(PrattCertificate {
+                prime,
+                witness: 1,
+                factors: Vec::new(),
+            });
+        }
+
+        let witness: u128 = self.number()?;
+        self.expect(';')?;
+        let mut factors: Vec<PrattCertificate> = vec![self.certificate(depth + 1)?];
+        while self.eat(',') {
+            factors.push(self.certificate(depth + 1)?);
+        }
+        self.expect(')')?;
+
+        Ok(PrattCertificate {
+            prime,
+            witness,
+            factors,
+        })
+    }
+
+    fn number(&mut self) -> Result<u128, ParsePrattCertificateError> {
+        self.skip_whitespace();
+        let rest: &str = &self.text[self.position..];
+        let length: usize = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+
+        let number: u128 = rest[..length].parse().map_err(|_| self.error())?;
+        self.position += length;
+        Ok(number)
+    }
+
+    fn eat(&mut self, symbol: char) -> bool {
+        self.skip_whitespace();
+        if self.text[self.position..].starts_with(symbol) {
+            self.position += symbol.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, symbol: char) -> Result<(), ParsePrattCertificateError> {
+        if self.eat(symbol) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest: &str = &self.text[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn error(&self) -> ParsePrattCertificateError {
+        ParsePrattCertificateError {
+            position: self.position,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn pratt_certificate_test() {
+        assert_eq!(PrattCertificate::new(0), None);
+        assert_eq!(PrattCertificate::new(1), None);
+        assert_eq!(PrattCertificate::new(2).unwrap().to_string(), "2");
+        assert_eq!(PrattCertificate::new(3).unwrap().to_string(), "3(2; 2)");
+        assert_eq!(PrattCertificate::new(561), None);
+
+        for prime in crate::sequences::primes(2_000) {
+            let certificate: PrattCertificate = PrattCertificate::new(prime as u128).unwrap();
+            assert_eq!(certificate.prime(), prime as u128);
+            assert!(certificate.verify());
+        }
+
+        for prime in [
+            18_446_744_073_709_551_557,
+            (1 << 61) - 1,
+            (1 << 89) - 1,
+            340_282_366_920_938_463_463_374_607_431_768_211_297,
+        ] {
+            let certificate: PrattCertificate = PrattCertificate::new(prime).unwrap();
+            assert!(certificate.verify());
+            assert_eq!(certificate.to_string().parse(), Ok(certificate));
+        }
+    }
+
+    #[test]
+    fn verify_test() {
+        let parse = |text: &str| text.parse::<PrattCertificate>().unwrap().verify();
+
+        assert!(parse("2"));
+        assert!(parse("13(2; 2, 3(2; 2))"));
+        // A composite number, a wrong witness, a missing or extra factor, an unproven factor.
+        assert!(!parse("1"));
+        assert!(!parse("4"));
+        assert!(!parse("9(2; 2)"));
+        assert!(!parse("13(3; 2, 3(2; 2))"));
+        assert!(!parse("13(2; 2)"));
+        assert!(!parse("13(2; 2, 3(2; 2), 5(2; 2))"));
+        assert!(!parse("13(2; 2, 3)"));
+        assert!(!parse("13(2; 2, 3(2; 2, 3))"));
+    }
+
+    #[test]
+    fn parse_test() {
+        let certificate: PrattCertificate = PrattCertificate::new(1_000_000_007).unwrap();
+        let text: String = certificate.to_string();
+        assert_eq!(text.parse(), Ok(certificate.clone()));
+        assert_eq!(
+            text.replace(' ', "").replace(',', " ,\n ").parse(),
+            Ok(certificate)
+        );
+
+        let error = |text: &str| text.parse::<PrattCertificate>().unwrap_err().position();
+        assert_eq!(error(""), 0);
+        assert_eq!(error("7(3 2)"), 4);
+        assert_eq!(error("7(3; 2, 3(2; 2)"), 15);
+        assert_eq!(error("7(3; 2, 3(2; 2))x"), 16);
+        assert_eq!(error("7(3;)"), 4);
+        assert_eq!(error("-7"), 0);
+
+        let nested = |depth: usize| "3(2; ".repeat(depth) + "2" + &")".repeat(depth);
+        assert!(nested(MAX_DEPTH).parse::<PrattCertificate>().is_ok());
+        assert_eq!(error(&nested(MAX_DEPTH + 1)), 5 * (MAX_DEPTH + 1));
+        assert_eq!(error(&nested(1_000_000)), 5 * (MAX_DEPTH + 1));
+    }
+}
@@ -23,6 +23,7 @@
 /// let sequence = fibonacci(until);
 /// assert_eq!(sequence, vec![1, 1, 2, 3, 5, 8, 13]);
 /// ```
+#[allow(clippy::doc_overindented_list_items)]
 pub fn fibonacci(until: usize) -> Vec<usize> {
     if until < 2 {
         panic!("Invalid parameter. Please use integers above 1.")
@@ -1,10 +1,15 @@
 /*!
- This module includes some mathmetical sequences and related calculations.
- */
+This module includes some mathmetical sequences and related calculations.
+*/
 
 pub mod arithmetic;
 pub mod fibonacci;
+pub mod prime_gaps;
+pub mod prime_iterator;
+pub mod prime_progressions;
 pub mod primes;
+#[cfg(feature = "parallel")]
+pub mod primes_parallel;
 pub mod squares;
 pub mod triangular_numbers;
 
@@ -13,9 +18,29 @@ pub use self::arithmetic::arithmetic;
 pub use self::fibonacci::fibonacci;
 pub use self::fibonacci::nth_fibonacci;
 
+pub use self::prime_gaps::cousin_primes;
+pub use self::prime_gaps::maximal_prime_gaps;
+pub use self::prime_gaps::next_prime;
+pub use self::prime_gaps::prev_prime;
+pub use self::prime_gaps::prime_tuples;
+pub use self::prime_gaps::sexy_primes;
+pub use self::prime_gaps::twin_primes;
+
+pub use self::prime_iterator::Primes;
+
+pub use self::prime_progressions::count_primes_in_progression;
+pub use self::prime_progressions::least_prime_in_progression;
+pub use self::prime_progressions::primes_in_progression;
+
 pub use self::primes::is_prime;
 pub use self::primes::nth_prime;
 pub use self::primes::primes;
+pub use self::primes::primes_in_range;
+
+#[cfg(feature = "parallel")]
+pub use self::primes_parallel::primes_in_range_parallel;
+#[cfg(feature = "parallel")]
+pub use self::primes_parallel::primes_parallel;
 
 pub use self::squares::square_numbers;
 
@@ -0,0 +1,296 @@
+use super::primes::{is_prime, primes_in_range};
+
+/// Finds the smallest prime number greater than a given number.
+///
+/// Given an unsigned integer (`number`), this function returns the first prime strictly above
+/// it, checking the candidates coprime to 6 with [`is_prime`]. It returns `None` if there is no
+/// such prime below `usize::MAX`.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned integer. The number to start searching after.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sequences::next_prime;
+///
+/// assert_eq!(next_prime(0), Some(2));
+/// assert_eq!(next_prime(13), Some(17));
+/// assert_eq!(next_prime(1_000_000_000_000), Some(1_000_000_000_039));
+/// ```
+pub fn next_prime(number: usize) -> Option<usize> {
+    match number {
+        0 | 1 => return Some(2),
+        2 => return Some(3),
+        3 | 4 => return Some(5),
+        _ => {}
+    }
+
+    // Walk the numbers of the form 6k - 1 and 6k + 1 above `number`.
+    let mut candidate: usize = number.checked_add(1)?;
+    while candidate % 6 != 1 && candidate % 6 != 5 {
+        candidate = candidate.checked_add(1)?;
+    }
+
+    loop {
+        if is_prime(candidate) {
+            return Some(candidate);
+        }
+        let step: usize = if candidate % 6 == 5 { 2 } else { 4 };
+        candidate = candidate.checked_add(step)?;
+    }
+}
+
+/// Finds the largest prime number less than a given number.
+///
+/// Given an unsigned integer (`number`), this function returns the last prime strictly below
+/// it, checking the candidates coprime to 6 with [`is_prime`]. It returns `None` if the number is
+/// 2 or less.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned integer. The number to start searching before.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sequences::prev_prime;
+///
+/// assert_eq!(prev_prime(2), None);
+/// assert_eq!(prev_prime(17), Some(13));
+/// assert_eq!(prev_prime(1_000_000_000_000), Some(999_999_999_989));
+/// ```
+pub fn prev_prime(number: usize) -> Option<usize> {
+    match number {
+        0..=2 => return None,
+        3 => return Some(2),
+        4 | 5 => return Some(3),
+        6 | 7 => return Some(5),
+        _ => {}
+    }
+
+    // Walk the numbers of the form 6k - 1 and 6k + 1 below `number`.
+    let mut candidate: usize = number - 1;
+    while candidate % 6 != 1 && candidate % 6 != 5 {
+        candidate -= 1;
+    }
+
+    loop {
+        if is_prime(candidate) {
+            return Some(candidate);
+        }
+        candidate -= if candidate % 6 == 1 { 2 } else { 4 };
+    }
+}
+
+/// Finds the record gaps between consecutive primes within a range.
+///
+/// Given the bounds (`low` and `high`), this function walks the consecutive primes of the range
+/// and returns every pair `(p, q)` whose gap `q - p` is larger than all the gaps before it in
+/// the range. Starting the range at 2 gives the maximal prime gaps.
+///
+/// # Arguments
+///
+/// * `low` - The lower bound of the range, inclusive.
+/// * `high` - The upper bound of the range, inclusive.
+///
+/// # Panics
+///
+/// This function will panic if `low` is greater than `high`.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sequences::maximal_prime_gaps;
+///
+/// let gaps = maximal_prime_gaps(2, 200);
+/// assert_eq!(gaps, vec![(2, 3), (3, 5), (7, 11), (23, 29), (89, 97), (113, 127)]);
+/// ```
+pub fn maximal_prime_gaps(low: usize, high: usize) -> Vec<(usize, usize)> {
+    let mut records: Vec<(usize, usize)> = Vec::new();
+    let mut largest: usize = 0;
+
+    for pair in primes_in_range(low, high).windows(2) {
+        if pair[1] - pair[0] > largest {
+            largest = pair[1] - pair[0];
+            records.push((pair[0], pair[1]));
+        }
+    }
+
+    records
+}
+
+/// Finds the twin primes within a range, the pairs of primes `(p, p + 2)`.
+///
+/// # Arguments
+///
+/// * `low` - The lower bound of the range, inclusive.
+/// * `high` - The upper bound of the range, inclusive. Both primes of a pair are in the range.
+///
+/// # Panics
+///
+/// This function will panic if `low` is greater than `high`.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sequences::twin_primes;
+///
+/// assert_eq!(twin_primes(1, 20), vec![(3, 5), (5, 7), (11, 13), (17, 19)]);
+/// ```
+pub fn twin_primes(low: usize, high: usize) -> Vec<(usize, usize)> {
+    prime_pairs(low, high, 2)
+}
+
+/// Finds the cousin primes within a range, the pairs of primes `(p, p + 4)`.
+///
+/// # Arguments
+///
+/// * `low` - The lower bound of the range, inclusive.
+/// * `high` - The upper bound of the range, inclusive. Both primes of a pair are in the range.
+///
+/// # Panics
+///
+/// This function will panic if `low` is greater than `high`.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sequences::cousin_primes;
+///
+/// assert_eq!(cousin_primes(1, 20), vec![(3, 7), (7, 11), (13, 17)]);
+/// ```
+pub fn cousin_primes(low: usize, high: usize) -> Vec<(usize, usize)> {
+    prime_pairs(low, high, 4)
+}
+
+/// Finds the sexy primes within a range, the pairs of primes `(p, p + 6)`.
+///
+/// # Arguments
+///
+/// * `low` - The lower bound of the range, inclusive.
+/// * `high` - The upper bound of the range, inclusive. Both primes of a pair are in the range.
+///
+/// # Panics
+///
+/// This function will panic if `low` is greater than `high`.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sequences::sexy_primes;
+///
+/// assert_eq!(sexy_primes(1, 20), vec![(5, 11), (7, 13), (11, 17), (13, 19)]);
+/// ```
+pub fn sexy_primes(low: usize, high: usize) -> Vec<(usize, usize)> {
+    prime_pairs(low, high, 6)
+}
+
+/// Finds the prime k-tuples of a given pattern within a range.
+///
+/// Given the bounds (`low` and `high`) and a list of offsets (`pattern`), this function returns
+/// every prime `p` such that `p + offset` is prime for each offset of the pattern, with all these
+/// primes in the range. The pattern `[0, 2, 6]` finds prime triplets of the form `(p, p + 2,
+/// p + 6)`, and `[0, 2, 6, 8]` prime quadruplets.
+///
+/// # Arguments
+///
+/// * `low` - The lower bound of the range, inclusive.
+/// * `high` - The upper bound of the range, inclusive.
+/// * `pattern` - The offsets of the tuple from its first element.
+///
+/// # Panics
+///
+/// This function will panic if `low` is greater than `high`.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sequences::prime_tuples;
+///
+/// assert_eq!(prime_tuples(1, 200, &[0, 2, 6, 8]), vec![5, 11, 101, 191]);
+/// ```
+pub fn prime_tuples(low: usize, high: usize, pattern: &[usize]) -> Vec<usize> {
+    let primes: Vec<usize> = primes_in_range(low, high);
+
+    primes
+        .iter()
+        .copied()
+        .filter(|&prime| {
+            pattern.iter().all(|&offset| {
+                prime
+                    .checked_add(offset)
+                    .is_some_and(|member| primes.binary_search(&member).is_ok())
+            })
+        })
+        .collect()
+}
+
+fn prime_pairs(low: usize, high: usize, difference: usize) -> Vec<(usize, usize)> {
+    prime_tuples(low, high, &[0, difference])
+        .into_iter()
+        .map(|prime| (prime, prime + difference))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn next_prime_test() {
+        let primes: Vec<usize> = crate::sequences::primes(10_000);
+        for number in 0..9_973 {
+            let expected: usize = primes[primes.partition_point(|&p| p <= number)];
+            assert_eq!(next_prime(number), Some(expected));
+        }
+        assert_eq!(
+            next_prime(18_446_744_073_709_551_556),
+            Some(18_446_744_073_709_551_557)
+        );
+        assert_eq!(next_prime(18_446_744_073_709_551_557), None);
+        assert_eq!(next_prime(usize::MAX), None);
+    }
+
+    #[test]
+    fn prev_prime_test() {
+        let primes: Vec<usize> = crate::sequences::primes(10_000);
+        assert_eq!(prev_prime(0), None);
+        for number in 3..10_000 {
+            let expected: usize = primes[primes.partition_point(|&p| p < number) - 1];
+            assert_eq!(prev_prime(number), Some(expected));
+        }
+        assert_eq!(prev_prime(usize::MAX), Some(18_446_744_073_709_551_557));
+    }
+
+    #[test]
+    fn maximal_prime_gaps_test() {
+        let gaps: Vec<usize> = maximal_prime_gaps(2, 1_000_000)
+            .iter()
+            .map(|&(p, q)| q - p)
+            .collect();
+        assert_eq!(
+            gaps,
+            vec![1, 2, 4, 6, 8, 14, 18, 20, 22, 34, 36, 44, 52, 72, 86, 96, 112, 114]
+        );
+        assert_eq!(maximal_prime_gaps(24, 28), vec![]);
+    }
+
+    #[test]
+    fn prime_pairs_test() {
+        assert_eq!(twin_primes(1, 1_000).len(), 35);
+        assert_eq!(twin_primes(5, 7), vec![(5, 7)]);
+        assert_eq!(twin_primes(5, 6), vec![]);
+        assert_eq!(cousin_primes(1, 100).len(), 8);
+        assert_eq!(sexy_primes(1, 100).len(), 15);
+    }
+
+    #[test]
+    fn prime_tuples_test() {
+        assert_eq!(prime_tuples(1, 50, &[0, 2, 6]), vec![5, 11, 17, 41]);
+        assert_eq!(prime_tuples(1, 50, &[0, 4, 6]), vec![7, 13, 37]);
+        assert_eq!(prime_tuples(1, 10, &[0]), vec![2, 3, 5, 7]);
+    }
+}
@@ -0,0 +1,151 @@
+use std::iter::FusedIterator;
+
+use super::primes::{SegmentedSieve, SEGMENT_SPAN};
+
+/// An unbounded iterator over the prime numbers.
+///
+/// The primes are produced by a segmented sieve that sieves the next block whenever the current
+/// one runs out, growing its base primes as needed, so no upper bound has to be chosen up front.
+/// The iterator behaves like a cursor sitting between two primes: [`next`](Iterator::next)
+/// returns the prime after the cursor and [`prev`](Primes::prev) the prime before it, each moving
+/// the cursor past the returned prime.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sequences::Primes;
+///
+/// let small: Vec<usize> = Primes::new().take_while(|&prime| prime < 20).collect();
+/// assert_eq!(small, vec![2, 3, 5, 7, 11, 13, 17, 19]);
+///
+/// let mut primes = Primes::new().skip_to(1_000_000);
+/// assert_eq!(primes.next(), Some(1_000_003));
+/// assert_eq!(primes.prev(), Some(1_000_003));
+/// assert_eq!(primes.prev(), Some(999_983));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Primes {
+    sieve: SegmentedSieve,
+    segment_start: usize,
+    segment_end: usize,
+    segment_primes: Vec<usize>,
+    index: usize,
+}
+
+impl Primes {
+    /// Creates an iterator starting before the first prime, 2.
+    pub fn new() -> Self {
+        Primes {
+            sieve: SegmentedSieve::new(),
+            segment_start: 0,
+            segment_end: 1,
+            segment_primes: Vec::new(),
+            index: 0,
+        }
+    }
+
+    /// Moves the cursor just before `number`, so the next prime returned is the smallest prime
+    /// greater than or equal to `number`, and the previous one the largest prime below it.
+    pub fn skip_to(mut self, number: usize) -> Self {
+        self.load(
+            number.max(2),
+            number.max(2).saturating_add(SEGMENT_SPAN - 1),
+        );
+        self
+    }
+
+    /// Returns the prime before the cursor and moves the cursor before it, or `None` if there is
+    /// no smaller prime.
+    pub fn prev(&mut self) -> Option<usize> {
+        while self.index == 0 {
+            if self.segment_start <= 2 {
+                return None;
+            }
+            let end: usize = self.segment_start - 1;
+            self.load(end.saturating_sub(SEGMENT_SPAN - 1).max(2), end);
+            self.index = self.segment_primes.len();
+        }
+
+        self.index -= 1;
+        Some(self.segment_primes[self.index])
+    }
+
+    /// Sieves the segment `start..=end` and places the cursor at its beginning.
+    fn load(&mut self, start: usize, end: usize) {
+        self.segment_primes.clear();
+        self.sieve.push_primes(start, end, &mut self.segment_primes);
+        self.segment_start = start;
+        self.segment_end = end;
+        self.index = 0;
+    }
+}
+
+impl Default for Primes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for Primes {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.index == self.segment_primes.len() {
+            if self.segment_end == usize::MAX {
+                return None;
+            }
+            let start: usize = self.segment_end + 1;
+            self.load(start, start.saturating_add(SEGMENT_SPAN - 1));
+        }
+
+        self.index += 1;
+        Some(self.segment_primes[self.index - 1])
+    }
+}
+
+impl FusedIterator for Primes {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn primes_iterator_test() {
+        let collected: Vec<usize> = Primes::new().take_while(|&p| p <= 1_000_000).collect();
+        assert_eq!(collected, crate::sequences::primes(1_000_000));
+        assert_eq!(Primes::default().nth(9_999), Some(104_729));
+    }
+
+    #[test]
+    fn skip_to_test() {
+        assert_eq!(Primes::new().skip_to(0).next(), Some(2));
+        assert_eq!(Primes::new().skip_to(97).next(), Some(97));
+        assert_eq!(
+            Primes::new()
+                .skip_to(1_000_000_000_000)
+                .take(2)
+                .collect::<Vec<usize>>(),
+            vec![1_000_000_000_039, 1_000_000_000_061]
+        );
+    }
+
+    #[test]
+    fn prev_test() {
+        let mut primes: Primes = Primes::new();
+        assert_eq!(primes.prev(), None);
+        assert_eq!(primes.next(), Some(2));
+        assert_eq!(primes.next(), Some(3));
+        assert_eq!(primes.prev(), Some(3));
+        assert_eq!(primes.prev(), Some(2));
+        assert_eq!(primes.prev(), None);
+
+        let mut primes: Primes = Primes::new().skip_to(1_000_000);
+        let mut descending: Vec<usize> = Vec::new();
+        while let Some(prime) = primes.prev() {
+            descending.push(prime);
+        }
+        descending.reverse();
+        assert_eq!(descending, crate::sequences::primes(1_000_000));
+    }
+}
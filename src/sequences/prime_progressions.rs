@@ -0,0 +1,354 @@
+use super::primes::SegmentedSieve;
+use crate::modular::{gcd, mod_inverse};
+
+/// Number of terms of the progression sieved at once, one byte each.
+const SEGMENT_TERMS: usize = 1 << 15;
+
+/// Generates the primes of an arithmetic progression within a range.
+///
+/// Given a residue and a modulus (`residue` and `modulus`) and the bounds (`low` and `high`), this
+/// function returns a vector (`Vec`) containing all primes `p` with `p ≡ residue (mod modulus)`
+/// and `low <= p <= high`, in ascending order. Only the terms of the progression are sieved,
+/// segment by segment: every base prime not dividing the modulus crosses out one term in each
+/// run of that many terms, so the work is proportional to the number of terms rather than to
+/// the length of the range.
+///
+/// # Arguments
+///
+/// * `residue` - The residue of the primes, taken modulo `modulus`.
+/// * `modulus` - The common difference of the progression.
+/// * `low` - The lower bound of the range, inclusive.
+/// * `high` - The upper bound of the range, inclusive.
+///
+/// # Panics
+///
+/// This function will panic if `modulus` is 0 or if `low` is greater than `high`.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sequences::primes_in_progression;
+///
+/// assert_eq!(primes_in_progression(3, 10, 0, 100), vec![3, 13, 23, 43, 53, 73, 83]);
+/// assert_eq!(primes_in_progression(4, 10, 0, 100), vec![]);
+/// assert_eq!(primes_in_progression(2, 10, 0, 100), vec![2]);
+/// ```
+pub fn primes_in_progression(
+    residue: usize,
+    modulus: usize,
+    low: usize,
+    high: usize,
+) -> Vec<usize> {
+    let mut result: Vec<usize> = Vec::new();
+    let mut sieve: SegmentedSieve = SegmentedSieve::new();
+    sieve_progression(&mut sieve, residue, modulus, low, high, |prime| {
+        result.push(prime);
+        true
+    });
+
+    result
+}
+
+/// Counts the primes of an arithmetic progression within a range.
+///
+/// Given a residue and a modulus (`residue` and `modulus`) and the bounds (`low` and `high`), this
+/// function returns the number of primes `p` with `p ≡ residue (mod modulus)` and
+/// `low <= p <= high`, sieving as [`primes_in_progression`] does without storing the primes.
+///
+/// # Arguments
+///
+/// * `residue` - The residue of the primes, taken modulo `modulus`.
+/// * `modulus` - The common difference of the progression.
+/// * `low` - The lower bound of the range, inclusive.
+/// * `high` - The upper bound of the range, inclusive.
+///
+/// # Panics
+///
+/// This function will panic if `modulus` is 0 or if `low` is greater than `high`.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sequences::count_primes_in_progression;
+///
+/// // The primes are shared evenly between the residues coprime to the modulus.
+/// assert_eq!(count_primes_in_progression(1, 4, 0, 1_000_000), 39_175);
+/// assert_eq!(count_primes_in_progression(3, 4, 0, 1_000_000), 39_322);
+/// ```
+pub fn count_primes_in_progression(
+    residue: usize,
+    modulus: usize,
+    low: usize,
+    high: usize,
+) -> usize {
+    let mut count: usize = 0;
+    let mut sieve: SegmentedSieve = SegmentedSieve::new();
+    sieve_progression(&mut sieve, residue, modulus, low, high, |_| {
+        count += 1;
+        true
+    });
+
+    count
+}
+
+/// Finds the least prime of an arithmetic progression.
+///
+/// Given a residue and a modulus (`residue` and `modulus`), this function returns the smallest
+/// prime `p` with `p ≡ residue (mod modulus)`. By Dirichlet's theorem such a prime exists
+/// whenever the residue is coprime to the modulus. Otherwise the only candidate is their common
+/// divisor, and `None` is returned if it is not a prime of the progression. The progression is
+/// sieved in windows that double in length until a prime is found.
+///
+/// # Arguments
+///
+/// * `residue` - The residue of the prime, taken modulo `modulus`.
+/// * `modulus` - The common difference of the progression.
+///
+/// # Panics
+///
+/// This function will panic if `modulus` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sequences::least_prime_in_progression;
+///
+/// assert_eq!(least_prime_in_progression(1, 1_000), Some(3_001));
+/// assert_eq!(least_prime_in_progression(5, 10), Some(5));
+/// assert_eq!(least_prime_in_progression(4, 10), None);
+/// ```
+pub fn least_prime_in_progression(residue: usize, modulus: usize) -> Option<usize> {
+    if modulus == 0 {
+        panic!("eratosthenes::least_prime_in_progression cannot work with a modulus of 0.");
+    }
+
+    // The base primes are kept from one window to the next and only grow as the windows do.
+    let mut sieve: SegmentedSieve = SegmentedSieve::new();
+    let mut least: Option<usize> = None;
+    let mut low: usize = 0;
+    let mut span: usize = 1 << 10;
+    loop {
+        let high: usize = modulus
+            .checked_mul(span)
+            .and_then(|length| low.checked_add(length))
+            .unwrap_or(usize::MAX);
+        sieve_progression(&mut sieve, residue, modulus, low, high, |prime| {
+            least = Some(prime);
+            false
+        });
+
+        if least.is_some()
+            || high == usize::MAX
+            || gcd((residue % modulus) as u64, modulus as u64) != 1
+        {
+            return least;
+        }
+        low = high + 1;
+        span = span.saturating_mul(2);
+    }
+}
+
+/// Calls `visit` with the primes `p ≡ residue (mod modulus)` within `low..=high`, in ascending
+/// order, until it returns `false`. The base primes are taken from `sieve`, which is grown to
+/// cover `high`.
+fn sieve_progression(
+    sieve: &mut SegmentedSieve,
+    residue: usize,
+    modulus: usize,
+    low: usize,
+    high: usize,
+    mut visit: impl FnMut(usize) -> bool,
+) {
+    if modulus == 0 {
+        panic!("eratosthenes::primes_in_progression cannot work with a modulus of 0.");
+    }
+    if low > high {
+        panic!(
+            "eratosthenes::primes_in_progression cannot work with a lower bound above the upper bound."
+        );
+    }
+
+    // The first term of the progression within the range.
+    let residue: usize = residue % modulus;
+    let first: usize = match low.checked_add((residue + modulus - low % modulus) % modulus) {
+        Some(first) if first <= high => first,
+        _ => return,
+    };
+
+    // A common divisor of the residue and the modulus divides every term, so only the divisor
+    // itself can be a prime of the progression.
+    let divisor: usize = gcd(residue as u64, modulus as u64) as usize;
+    if divisor > 1 {
+        if divisor % modulus == residue
+            && low <= divisor
+            && divisor <= high
+            && super::is_prime(divisor)
+        {
+            visit(divisor);
+        }
+        return;
+    }
+
+    let terms: usize = (high - first) / modulus + 1;
+    let term = |index: usize| first + index * modulus;
+
+    // Sieving costs at least one step for each of the about `r / ln r` base primes up to the
+    // square root `r` of the bound, so a progression with fewer terms than that is cheaper to
+    // walk with a primality test on every term.
+    let root: usize = crate::isqrt(high as u128) as usize;
+    if (terms as f64) < root as f64 / (root as f64).ln() {
+        for index in 0..terms {
+            let number: usize = term(index);
+            if super::is_prime(number) && !visit(number) {
+                return;
+            }
+        }
+        return;
+    }
+
+    // For each base prime, the index of the next term it crosses out. Terms are multiples of the
+    // prime every `prime` indices, starting from the index solving `first + index * modulus ≡ 0`,
+    // and the multiples below the square of the prime are skipped as they have smaller factors
+    // or are the prime itself. The primes crossing out no term within the range are left out.
+    sieve.grow_base_primes(high);
+    let mut crossings: Vec<(usize, usize)> = sieve
+        .base_primes()
+        .iter()
+        .copied()
+        .take_while(|&prime| prime <= root)
+        .filter(|&prime| modulus % prime != 0)
+        .map(|prime| {
+            let steps: usize = (prime - first % prime) % prime;
+            let index: usize =
+                steps * mod_inverse(modulus as u64, prime as u64).unwrap() as usize % prime;
+            let square: u128 = prime as u128 * prime as u128;
+            let stride: u128 = prime as u128 * modulus as u128;
+            let below: u128 =
+                square.saturating_sub(first as u128 + index as u128 * modulus as u128);
+            let skipped: u128 = (below + stride - 1) / stride * prime as u128;
+            (prime, (index as u128 + skipped).min(terms as u128) as usize)
+        })
+        .filter(|&(_, index)| index < terms)
+        .collect();
+
+    let mut segment: Vec<bool> = vec![true; terms.min(SEGMENT_TERMS)];
+    let mut start: usize = 0;
+    while start < terms {
+        let end: usize = terms.min(start + SEGMENT_TERMS);
+        segment.fill(true);
+
+        for (prime, index) in crossings.iter_mut() {
+            while *index < end {
+                segment[*index - start] = false;
+                *index += *prime;
+            }
+        }
+
+        for (offset, &is_prime) in segment[..end - start].iter().enumerate() {
+            let number: usize = term(start + offset);
+            if is_prime && number >= 2 && !visit(number) {
+                return;
+            }
+        }
+
+        start = end;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn primes_in_progression_test() {
+        let primes: Vec<usize> = crate::sequences::primes(2_000_000);
+        for &(residue, modulus, low, high) in &[
+            (0, 1, 0, 1_000),
+            (1, 2, 0, 1_000_000),
+            (1, 4, 10, 2_000_000),
+            (3, 4, 0, 2_000_000),
+            (7, 30, 500, 1_900_000),
+            (1, 1_000, 0, 2_000_000),
+            (999, 1_000, 123, 1_234_567),
+            (12_345, 65_536, 0, 2_000_000),
+            (3, 9, 0, 100),
+            (2, 6, 0, 100),
+            (5, 5, 0, 100),
+        ] {
+            let expected: Vec<usize> = primes
+                .iter()
+                .copied()
+                .filter(|&p| p % modulus == residue % modulus && low <= p && p <= high)
+                .collect();
+            assert_eq!(primes_in_progression(residue, modulus, low, high), expected);
+            assert_eq!(
+                count_primes_in_progression(residue, modulus, low, high),
+                expected.len()
+            );
+        }
+
+        assert_eq!(primes_in_progression(1, 4, 6, 12), vec![]);
+        assert_eq!(primes_in_progression(2, 4, 2, 2), vec![2]);
+        assert_eq!(primes_in_progression(2, 4, 3, 100), vec![]);
+
+        // Few terms below a large bound are tested one by one instead of sieved.
+        let high: usize = usize::MAX;
+        let low: usize = high - (1 << 42);
+        let expected: Vec<usize> = (low + 2..=high)
+            .step_by(1 << 40)
+            .filter(|&number| crate::sequences::is_prime(number))
+            .collect();
+        assert_eq!(primes_in_progression(1, 1 << 40, low, high), expected);
+        assert_eq!(
+            primes_in_progression(7, 1_000_000_000_000, 0, 10_000_000_000_000),
+            (0..10)
+                .map(|index: usize| 7 + index * 1_000_000_000_000)
+                .filter(|&number| crate::sequences::is_prime(number))
+                .collect::<Vec<usize>>()
+        );
+        assert_eq!(
+            primes_in_progression(1, 1_000_000, 1_000_000_000_000, 1_000_100_000_000),
+            crate::sequences::primes_in_range(1_000_000_000_000, 1_000_100_000_000)
+                .into_iter()
+                .filter(|p| p % 1_000_000 == 1)
+                .collect::<Vec<usize>>()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn primes_in_progression_fail() {
+        primes_in_progression(1, 0, 0, 100);
+    }
+
+    #[test]
+    fn least_prime_in_progression_test() {
+        assert_eq!(least_prime_in_progression(0, 1), Some(2));
+        assert_eq!(least_prime_in_progression(1, 2), Some(3));
+        assert_eq!(least_prime_in_progression(0, 2), Some(2));
+        assert_eq!(least_prime_in_progression(0, 4), None);
+        assert_eq!(least_prime_in_progression(6, 9), None);
+        assert_eq!(least_prime_in_progression(13, 10), Some(3));
+        assert_eq!(least_prime_in_progression(1, 1_000), Some(3_001));
+        assert_eq!(
+            least_prime_in_progression(1, 1_000_000_000),
+            Some(6_000_000_001)
+        );
+
+        let primes: Vec<usize> = crate::sequences::primes(100_000);
+        for modulus in 1..=200 {
+            for residue in 0..modulus {
+                let expected: Option<usize> =
+                    primes.iter().copied().find(|&p| p % modulus == residue);
+                assert_eq!(least_prime_in_progression(residue, modulus), expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn least_prime_in_progression_fail() {
+        least_prime_in_progression(1, 0);
+    }
+}
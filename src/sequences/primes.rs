@@ -1,13 +1,24 @@
+use crate::sieve::wheel::{push_wheel_primes, sieve_wheel_segment, WHEEL_PRIMES};
+
+/// Number of wheel bytes sieved at once by the segmented sieve, each covering 30 integers.
+/// 32 KiB fits in the L1 data cache of most processors.
+pub(crate) const SEGMENT_BYTES: usize = 1 << 15;
+
+/// Number of integers covered by one segment of the segmented sieve.
+pub(crate) const SEGMENT_SPAN: usize = SEGMENT_BYTES * 30;
 
 /// Generates prime numbers up to a specified limit using the Sieve of Eratosthenes algorithm.
 ///
 /// Given the limit (`until`), this function returns a vector (`Vec`) containing all prime
-/// numbers less than or equal to the specified limit.
+/// numbers less than or equal to the specified limit. The sieving is done segment by segment
+/// with [`primes_in_range`], so the memory used besides the output stays proportional to the
+/// square root of the limit. Within a segment only the integers coprime to 30 are stored, one bit
+/// each.
 ///
 /// # Arguments
 ///
 /// * `until` - The limit for generating prime numbers. The sequence will contain all prime
-///             numbers less than or equal to this limit.
+///   numbers less than or equal to this limit.
 ///
 /// # Panics
 ///
@@ -28,39 +39,146 @@ pub fn primes(until: usize) -> Vec<usize> {
         panic!("There are nor prime numbers under 2.");
     };
 
-    let mut sieve: Vec<bool> = vec![true; until + 1];
-    sieve[0] = false;
-    sieve[1] = false;
-
-    let mut number: usize = 2;
-    while number * number <= until {
-        if sieve[number] {
-            let mut multiple: usize = number * number;
-            while multiple <= until {
-                sieve[multiple] = false;
-                multiple += number;
+    primes_in_range(2, until)
+}
+
+/// Generates the prime numbers within a range using a segmented Sieve of Eratosthenes.
+///
+/// Given the bounds (`low` and `high`), this function returns a vector (`Vec`) containing all
+/// prime numbers `p` with `low <= p <= high`, in ascending order. The base primes up to the
+/// square root of `high` are sieved once, then the range is processed in fixed-size blocks, so
+/// the memory used stays proportional to the segment size rather than to `high`.
+///
+/// # Arguments
+///
+/// * `low` - The lower bound of the range, inclusive.
+/// * `high` - The upper bound of the range, inclusive.
+///
+/// # Panics
+///
+/// This function will panic if `low` is greater than `high`.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sequences::primes_in_range;
+///
+/// let prime_sequence = primes_in_range(1_000_000_000_000, 1_000_000_000_100);
+/// assert_eq!(prime_sequence, vec![1_000_000_000_039, 1_000_000_000_061, 1_000_000_000_063, 1_000_000_000_091]);
+/// ```
+pub fn primes_in_range(low: usize, high: usize) -> Vec<usize> {
+    if low > high {
+        panic!(
+            "eratosthenes::primes_in_range cannot work with a lower bound above the upper bound."
+        );
+    }
+
+    let mut result: Vec<usize> = Vec::new();
+    SegmentedSieve::new().push_primes(low, high, &mut result);
+
+    result
+}
+
+/// A segmented sieve over the mod-30 wheel, reusable for many ranges.
+///
+/// Every range is sieved in blocks of [`SEGMENT_BYTES`] bytes, and the base primes grow as the
+/// ranges reach further.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SegmentedSieve {
+    base_primes: Vec<usize>,
+    base_limit: usize,
+    bytes: Vec<u8>,
+}
+
+impl SegmentedSieve {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends the primes within `low..=high` to `primes`, in ascending order.
+    pub(crate) fn push_primes(&mut self, low: usize, high: usize, primes: &mut Vec<usize>) {
+        primes.extend(
+            WHEEL_PRIMES
+                .iter()
+                .filter(|&&prime| low <= prime && prime <= high),
+        );
+        if high < 7 || low > high {
+            return;
+        }
+
+        self.grow_base_primes(high);
+        self.bytes.resize(SEGMENT_BYTES, 0);
+
+        let last: usize = high / 30;
+        let mut first: usize = low / 30;
+        loop {
+            let end: usize = first.saturating_add(SEGMENT_BYTES - 1).min(last);
+            let bytes: &mut [u8] = &mut self.bytes[..end - first + 1];
+
+            sieve_wheel_segment(bytes, first, &self.base_primes);
+            push_wheel_primes(bytes, first, low, high, primes);
+
+            if end == last {
+                break;
             }
+            first = end + 1;
+        }
+    }
+
+    /// Sieves the base primes up to at least the square root of `high`, at least doubling them.
+    pub(crate) fn grow_base_primes(&mut self, high: usize) {
+        let root: usize = crate::isqrt(high as u128) as usize;
+        if root > self.base_limit {
+            self.base_limit = root
+                .max(self.base_limit.saturating_mul(2))
+                .min(u32::MAX as usize);
+            self.base_primes = small_primes(self.base_limit);
         }
-        number += 1;
     }
 
-    let primes: Vec<usize> = sieve
-        .iter()
-        .enumerate()
-        .filter(|(_, &is_prime)| is_prime)
-        .map(|(number, _)| number)
-        .collect();
+    /// Returns the base primes sieved so far, all the primes up to at least the square root of
+    /// the last `high` given to [`grow_base_primes`](Self::grow_base_primes).
+    pub(crate) fn base_primes(&self) -> &[usize] {
+        &self.base_primes
+    }
+}
+
+/// Sieves the primes up to `until` segment by segment on the mod-30 wheel, with the base primes
+/// up to its square root found the same way, so only one segment is held besides the primes.
+/// Only meant for the small base primes of the segmented sieve.
+pub(crate) fn small_primes(until: usize) -> Vec<usize> {
+    if until < 2 {
+        return Vec::new();
+    }
+
+    let root: usize = crate::isqrt(until as u128) as usize;
+    let mut sieve: SegmentedSieve = SegmentedSieve {
+        base_primes: small_primes(root),
+        base_limit: root,
+        bytes: Vec::new(),
+    };
+
+    let mut primes: Vec<usize> = Vec::new();
+    sieve.push_primes(0, until, &mut primes);
 
     primes
 }
 
 /// Finds the nth prime number.
 ///
-/// Given an unsigned integer (`nth`), this function finds and returns the nth prime number.
+/// Given an unsigned integer (`nth`), this function finds and returns the nth prime number. The
+/// prime is first estimated with Cipolla's asymptotic formula, kept within Dusart's and Rosser's
+/// bounds `n (ln n + ln ln n - 1) < p_n < n (ln n + ln ln n)`. The primes up to the estimate are
+/// counted with [`prime_count`](crate::counting::prime_count), then a segmented sieve walks
+/// forwards or backwards from the estimate until the count reaches `nth`.
 ///
 /// # Arguments
 ///
-/// * `nth` - An unsigned integer. The position of the prime number to find.
+/// * `nth` - An unsigned integer. The position of the prime number to find, starting from 1.
+///
+/// # Panics
+///
+/// This function will panic if `nth` is 0.
 ///
 /// # Examples
 ///
@@ -72,27 +190,75 @@ pub fn primes(until: usize) -> Vec<usize> {
 /// assert_eq!(prime, 29);
 /// ```
 pub fn nth_prime(nth: usize) -> usize {
-    if nth == 1 {
-        return 2;
+    if nth == 0 {
+        panic!("eratosthenes::nth_prime counts primes starting from 1.");
+    }
+
+    if nth < 6 {
+        return [2, 3, 5, 7, 11][nth - 1];
     }
 
-    let mut primes = vec![2];
-    let mut number = 3;
+    let n: f64 = nth as f64;
+    let log: f64 = n.ln();
+    let log_log: f64 = log.ln();
+    let lower: usize = (n * (log + log_log - 1.0)) as usize;
+    let upper: usize = (n * (log + log_log)) as usize + 1;
+    let estimate: usize =
+        ((n * (log + log_log - 1.0 + (log_log - 2.0) / log)) as usize).clamp(lower, upper);
+
+    let mut sieve: SegmentedSieve = SegmentedSieve::new();
+    let mut segment: Vec<usize> = Vec::new();
+    let mut count: usize = crate::counting::prime_count(estimate);
+
+    if count >= nth {
+        // The largest prime up to the estimate is prime number `count`, walk down from it.
+        let mut end: usize = estimate;
+        loop {
+            let start: usize = end.saturating_sub(SEGMENT_SPAN - 1).max(lower);
 
-    while primes.len() < nth {
-        if primes.iter().all(|&prime| number % prime != 0) {
-            primes.push(number);
+            segment.clear();
+            sieve.push_primes(start, end, &mut segment);
+            for &prime in segment.iter().rev() {
+                if count == nth {
+                    return prime;
+                }
+                count -= 1;
+            }
+
+            if start == lower {
+                panic!("eratosthenes::nth_prime went past its lower bound.");
+            }
+            end = start - 1;
         }
-        number += 2;
     }
 
-    *primes.last().unwrap()
+    let mut start: usize = estimate + 1;
+    loop {
+        let end: usize = start.saturating_add(SEGMENT_SPAN - 1).min(upper);
+
+        segment.clear();
+        sieve.push_primes(start, end, &mut segment);
+        if let Some(&prime) = segment.get(nth - count - 1) {
+            return prime;
+        }
+        count += segment.len();
+
+        if end == upper {
+            panic!("eratosthenes::nth_prime went past its upper bound.");
+        }
+        start = end + 1;
+    }
 }
 
+/// Numbers below this limit are checked by trial division, anything above it goes through the
+/// Miller–Rabin test.
+const TRIAL_DIVISION_LIMIT: usize = 1 << 16;
+
 /// Checks if a number is prime.
 ///
 /// Given an unsigned integer (`number`), this function checks if it is a prime number and returns
-/// a boolean indicating the result.
+/// a boolean indicating the result. Small numbers are checked by trial division, larger ones with
+/// the deterministic [`miller_rabin`](crate::primality::miller_rabin) test.
 ///
 /// # Arguments
 ///
@@ -112,11 +278,16 @@ pub fn is_prime(number: usize) -> bool {
         return false;
     }
 
-    let sqrt = (number as f64).sqrt() as usize;
-    for index in 2..=sqrt {
+    if number >= TRIAL_DIVISION_LIMIT {
+        return crate::primality::miller_rabin(number as u64);
+    }
+
+    let mut index: usize = 2;
+    while index * index <= number {
         if number % index == 0 {
             return false;
         }
+        index += 1;
     }
 
     true
@@ -147,12 +318,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn small_primes_test() {
+        for until in 0..1_000 {
+            let expected: Vec<usize> = (0..=until).filter(|&n| is_prime(n)).collect();
+            assert_eq!(small_primes(until), expected);
+        }
+        assert_eq!(small_primes(65_535).len(), 6_542);
+        assert_eq!(small_primes(10_000_000).len(), 664_579);
+    }
+
+    #[test]
+    fn primes_in_range_test() {
+        assert_eq!(primes_in_range(0, 1), vec![]);
+        assert_eq!(primes_in_range(0, 10), vec![2, 3, 5, 7]);
+        assert_eq!(primes_in_range(14, 16), vec![]);
+        assert_eq!(primes_in_range(97, 97), vec![97]);
+        assert_eq!(primes_in_range(2, 100_000), small_primes(100_000));
+        assert_eq!(
+            primes_in_range(1_000_000_000_000, 1_000_000_000_100),
+            vec![
+                1_000_000_000_039,
+                1_000_000_000_061,
+                1_000_000_000_063,
+                1_000_000_000_091
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn primes_in_range_fail() {
+        primes_in_range(10, 9);
+    }
+
     #[test]
     fn nth_prime_test() {
         assert_eq!(nth_prime(10), 29);
         assert_eq!(nth_prime(1), 2);
         assert_eq!(nth_prime(2), 3);
         assert_eq!(nth_prime(1000), 7919);
+        for (index, &prime) in primes(10_000).iter().enumerate() {
+            assert_eq!(nth_prime(index + 1), prime);
+        }
+        assert_eq!(nth_prime(1_000_000), 15_485_863);
+        assert_eq!(nth_prime(10_000_000), 179_424_673);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nth_prime_fail() {
+        nth_prime(0);
     }
 
     #[test]
@@ -160,5 +376,9 @@ mod tests {
         assert!(!is_prime(10));
         assert!(is_prime(13));
         assert!(!is_prime(1));
+        assert!(is_prime(65_537));
+        assert!(!is_prime(4_294_967_297));
+        assert!(is_prime(18_446_744_073_709_551_557));
+        assert!(!is_prime(usize::MAX));
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,160 @@
+use std::num::NonZeroUsize;
+use std::thread;
+
+use super::primes::{SegmentedSieve, SEGMENT_SPAN};
+
+/// Generates prime numbers up to a specified limit, sieving on every available core.
+///
+/// Given the limit (`until`), this function returns the same vector (`Vec`) as
+/// [`primes`](super::primes), containing all prime numbers less than or equal to the limit. The
+/// work is split as in [`primes_in_range_parallel`].
+///
+/// # Arguments
+///
+/// * `until` - The limit for generating prime numbers. The sequence will contain all prime
+///   numbers less than or equal to this limit.
+///
+/// # Panics
+///
+/// This function will panic if the `until` parameter is less than 2, as there are no prime
+/// numbers below 2.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sequences::{primes, primes_parallel};
+///
+/// assert_eq!(primes_parallel(20), vec![2, 3, 5, 7, 11, 13, 17, 19]);
+/// assert_eq!(primes_parallel(10_000_000), primes(10_000_000));
+/// ```
+pub fn primes_parallel(until: usize) -> Vec<usize> {
+    if until < 2 {
+        panic!("There are nor prime numbers under 2.");
+    };
+
+    primes_in_range_parallel(2, until)
+}
+
+/// Generates the prime numbers within a range, sieving on every available core.
+///
+/// Given the bounds (`low` and `high`), this function returns the same vector (`Vec`) as
+/// [`primes_in_range`](super::primes_in_range). The base primes are sieved once, then the range
+/// is cut into one contiguous block per worker, each sieved segment by segment on its own scoped
+/// thread, and the blocks are joined back in order. Ranges too short to fill two segments are
+/// sieved on the calling thread.
+///
+/// # Arguments
+///
+/// * `low` - The lower bound of the range, inclusive.
+/// * `high` - The upper bound of the range, inclusive.
+///
+/// # Panics
+///
+/// This function will panic if `low` is greater than `high`.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sequences::{primes_in_range, primes_in_range_parallel};
+///
+/// let low = 1_000_000_000_000;
+/// let high = 1_000_010_000_000;
+/// assert_eq!(primes_in_range_parallel(low, high), primes_in_range(low, high));
+/// ```
+pub fn primes_in_range_parallel(low: usize, high: usize) -> Vec<usize> {
+    let workers: usize = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+
+    primes_in_range_with_workers(low, high, workers)
+}
+
+/// Sieves `low..=high` on at most `workers` threads.
+fn primes_in_range_with_workers(low: usize, high: usize, workers: usize) -> Vec<usize> {
+    if low > high {
+        panic!(
+            "eratosthenes::primes_in_range_parallel cannot work with a lower bound above the upper bound."
+        );
+    }
+
+    let mut sieve: SegmentedSieve = SegmentedSieve::new();
+    sieve.grow_base_primes(high);
+
+    // Every worker gets a whole number of segments, so the blocks cost about the same.
+    let segments: usize = (high - low) / SEGMENT_SPAN + 1;
+    let workers: usize = workers.clamp(1, segments);
+    let block: usize = (segments + workers - 1) / workers * SEGMENT_SPAN;
+
+    let mut result: Vec<usize> = Vec::new();
+    if workers == 1 {
+        sieve.push_primes(low, high, &mut result);
+        return result;
+    }
+
+    let blocks: Vec<Vec<usize>> = thread::scope(|scope| {
+        let handles: Vec<thread::ScopedJoinHandle<Vec<usize>>> = (0..workers)
+            .map_while(|worker| low.checked_add(worker.checked_mul(block)?))
+            .take_while(|&start| start <= high)
+            .map(|start| {
+                let end: usize = start.saturating_add(block - 1).min(high);
+                let mut sieve: SegmentedSieve = sieve.clone();
+                scope.spawn(move || {
+                    let mut primes: Vec<usize> = Vec::new();
+                    sieve.push_primes(start, end, &mut primes);
+                    primes
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    result.reserve_exact(blocks.iter().map(Vec::len).sum());
+    for primes in blocks {
+        result.extend(primes);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn primes_parallel_test() {
+        assert_eq!(primes_parallel(2), vec![2]);
+        assert_eq!(primes_parallel(20), vec![2, 3, 5, 7, 11, 13, 17, 19]);
+        assert_eq!(
+            primes_parallel(10_000_000),
+            crate::sequences::primes(10_000_000)
+        );
+    }
+
+    #[test]
+    fn primes_in_range_parallel_test() {
+        let expected: Vec<usize> = crate::sequences::primes_in_range(0, 5_000_000);
+        for workers in 1..=9 {
+            assert_eq!(
+                primes_in_range_with_workers(0, 5_000_000, workers),
+                expected
+            );
+        }
+
+        let low: usize = 1_000_000_000_000;
+        let high: usize = low + 3 * SEGMENT_SPAN + 7;
+        assert_eq!(
+            primes_in_range_with_workers(low, high, 4),
+            crate::sequences::primes_in_range(low, high)
+        );
+        assert_eq!(primes_in_range_parallel(24, 28), vec![]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn primes_in_range_parallel_fail() {
+        primes_in_range_parallel(10, 9);
+    }
+}
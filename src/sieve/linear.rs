@@ -0,0 +1,121 @@
+use crate::factorization::Factorization;
+
+/// Builds the table of smallest prime factors of every integer up to a limit with a linear sieve.
+///
+/// Given the limit (`until`), this function returns a vector (`Vec`) whose entry at index `n` is
+/// the smallest prime dividing `n`, for every `n` up to and including the limit. The entries at
+/// index 0 and 1 are 0 and 1. This is the linear sieve of Euler: every composite is crossed out
+/// exactly once, by its smallest prime factor, so the table is built in linear time.
+///
+/// # Arguments
+///
+/// * `until` - The largest integer in the table.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sieve::smallest_prime_factors;
+///
+/// let table = smallest_prime_factors(12);
+/// assert_eq!(table, vec![0, 1, 2, 3, 2, 5, 2, 7, 2, 3, 2, 11, 2]);
+/// ```
+pub fn smallest_prime_factors(until: usize) -> Vec<usize> {
+    let mut table: Vec<usize> = vec![0; until + 1];
+    if until >= 1 {
+        table[1] = 1;
+    }
+
+    let mut primes: Vec<usize> = Vec::new();
+    for number in 2..=until {
+        if table[number] == 0 {
+            table[number] = number;
+            primes.push(number);
+        }
+
+        let smallest: usize = table[number];
+        for &prime in primes.iter() {
+            if prime > smallest || prime * number > until {
+                break;
+            }
+            table[prime * number] = prime;
+        }
+    }
+
+    table
+}
+
+/// Factorizes a number by repeatedly dividing it by its smallest prime factor.
+///
+/// Given an unsigned integer (`number`) and a table built by [`smallest_prime_factors`], this
+/// function returns the [`Factorization`] of the number in O(log n) steps.
+///
+/// # Arguments
+///
+/// * `number` - An unsigned integer. The number to factorize.
+/// * `table` - A smallest prime factor table covering `number`.
+///
+/// # Panics
+///
+/// This function will panic if the input `number` is 0 or not covered by the table.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sieve::{factorize_with_spf, smallest_prime_factors};
+///
+/// let table = smallest_prime_factors(1_000);
+/// assert_eq!(factorize_with_spf(360, &table).to_string(), "2^3 * 3^2 * 5");
+/// ```
+pub fn factorize_with_spf(number: usize, table: &[usize]) -> Factorization {
+    if number == 0 || number >= table.len() {
+        panic!(
+            "eratosthenes::sieve::factorize_with_spf needs a number between 1 and the table limit."
+        );
+    }
+
+    let mut primes: Vec<u128> = Vec::new();
+    let mut remaining: usize = number;
+    while remaining > 1 {
+        let prime: usize = table[remaining];
+        primes.push(prime as u128);
+        remaining /= prime;
+    }
+
+    Factorization::from_prime_factors(primes)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn smallest_prime_factors_test() {
+        assert_eq!(smallest_prime_factors(0), vec![0]);
+        assert_eq!(smallest_prime_factors(1), vec![0, 1]);
+
+        let table: Vec<usize> = smallest_prime_factors(100_000);
+        let primes: Vec<usize> = (2..=100_000).filter(|&n| table[n] == n).collect();
+        assert_eq!(primes, crate::sequences::primes(100_000));
+        assert_eq!(table[99_991], 99_991);
+        assert_eq!(table[317 * 313], 313);
+    }
+
+    #[test]
+    fn factorize_with_spf_test() {
+        let table: Vec<usize> = smallest_prime_factors(10_000);
+        assert!(factorize_with_spf(1, &table).is_one());
+        for number in 1..=10_000 {
+            assert_eq!(
+                factorize_with_spf(number, &table),
+                Factorization::new(number as u128)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn factorize_with_spf_fail() {
+        factorize_with_spf(11, &smallest_prime_factors(10));
+    }
+}
@@ -0,0 +1,13 @@
+/*!
+This module includes sieves producing tables about every integer up to a limit.
+*/
+
+pub mod linear;
+pub mod prime_table;
+pub(crate) mod wheel;
+
+pub use self::linear::factorize_with_spf;
+pub use self::linear::smallest_prime_factors;
+
+pub use self::prime_table::shared_sieve;
+pub use self::prime_table::Sieve;
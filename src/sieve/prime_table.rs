@@ -0,0 +1,551 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::ops::{Bound, RangeBounds};
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+
+use super::wheel::{
+    mask_up_to, position, push_wheel_primes, sieve_wheel_segment, RESIDUES, WHEEL_PRIMES,
+};
+use crate::sequences::primes::{small_primes, SEGMENT_BYTES};
+
+/// First bytes of every file written by [`Sieve::save`].
+const FILE_MAGIC: [u8; 8] = *b"ERATOSIV";
+
+/// Version of the file format written by [`Sieve::save`].
+const FILE_VERSION: u16 = 1;
+
+/// Modulus of the wheel whose residues are stored in the files.
+const FILE_WHEEL: u16 = 30;
+
+/// Size in bytes of the header preceding the bitmap in the files.
+const FILE_HEADER_BYTES: u64 = 8 + 2 + 2 + 8 + 8 + 8;
+
+/// [`Sieve::is_prime`] does not extend the table past this limit, about 36 MB of bitmap, and
+/// tests larger numbers with [`miller_rabin`](crate::primality::miller_rabin) instead.
+const IS_PRIME_GROWTH_LIMIT: usize = 1 << 30;
+
+/// A growable table of the primes up to a limit, answering primality queries from memory.
+///
+/// The table is a bitset over the mod-30 wheel, one bit for each integer coprime to 30, so it
+/// needs one byte per 30 integers covered. Every query that reaches past the current limit first
+/// extends the table, at least doubling it, by sieving the new part segment by segment, so a
+/// query about a number `n` may allocate about `n / 15` bytes. Only [`is_prime`](Sieve::is_prime)
+/// stops growing the table at 2^30.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sieve::Sieve;
+///
+/// let mut sieve = Sieve::new();
+/// assert!(sieve.is_prime(1_000_003));
+/// assert_eq!(sieve.count_up_to(1_000_000), 78_498);
+/// assert_eq!(sieve.nth(10_001), 104_743);
+/// assert_eq!(sieve.primes_in(90..=110), vec![97, 101, 103, 107, 109]);
+/// assert_eq!(sieve.smallest_factor(1_000_001), 101);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sieve {
+    limit: usize,
+    bytes: Vec<u8>,
+}
+
+impl Sieve {
+    /// Creates an empty table, which grows with the first query.
+    pub fn new() -> Self {
+        Sieve {
+            limit: 1,
+            bytes: vec![0],
+        }
+    }
+
+    /// Creates a table covering every integer up to and including `limit`.
+    pub fn with_limit(limit: usize) -> Self {
+        let mut sieve: Sieve = Sieve::new();
+        sieve.extend_to(limit);
+        sieve
+    }
+
+    /// Returns the largest integer currently covered by the table.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Extends the table so that it covers every integer up to and including `limit`. The table
+    /// at least doubles, so a series of growing queries only sieves each integer a few times.
+    pub fn extend_to(&mut self, limit: usize) {
+        if limit <= self.limit {
+            return;
+        }
+
+        let limit: usize = limit.max(self.limit.saturating_mul(2));
+        let base_primes: Vec<usize> = small_primes(crate::isqrt(limit as u128) as usize);
+        let last: usize = limit / 30;
+        self.bytes.resize(last + 1, 0);
+
+        // The byte holding the old limit may cover integers above it, which were sieved with too
+        // few base primes, so it is sieved again.
+        let mut first: usize = (self.limit + 1) / 30;
+        loop {
+            let end: usize = first.saturating_add(SEGMENT_BYTES - 1).min(last);
+            sieve_wheel_segment(&mut self.bytes[first..=end], first, &base_primes);
+
+            if end == last {
+                break;
+            }
+            first = end + 1;
+        }
+
+        self.limit = limit;
+    }
+
+    /// Checks if a number is prime, extending the table to it if needed. Numbers above both the
+    /// limit and 2^30 are tested with [`miller_rabin`](crate::primality::miller_rabin) instead,
+    /// leaving the table as it is.
+    pub fn is_prime(&mut self, number: usize) -> bool {
+        if number > self.limit && number > IS_PRIME_GROWTH_LIMIT {
+            return crate::primality::miller_rabin(number as u64);
+        }
+
+        self.extend_to(number);
+        match position(number) {
+            Some((byte, mask)) => self.bytes[byte] & mask != 0,
+            None => WHEEL_PRIMES.contains(&number),
+        }
+    }
+
+    /// Returns the primes within a range, in ascending order, extending the table to its end.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the range has no upper bound.
+    pub fn primes_in(&mut self, range: impl RangeBounds<usize>) -> Vec<usize> {
+        let low: usize = match range.start_bound() {
+            Bound::Included(&low) => low,
+            Bound::Excluded(&low) => low.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let high: usize = match range.end_bound() {
+            Bound::Included(&high) => high,
+            Bound::Excluded(&0) => return Vec::new(),
+            Bound::Excluded(&high) => high - 1,
+            Bound::Unbounded => {
+                panic!("eratosthenes::sieve::Sieve::primes_in needs a bounded range.")
+            }
+        };
+        if low > high {
+            return Vec::new();
+        }
+
+        self.extend_to(high);
+        let mut primes: Vec<usize> = WHEEL_PRIMES
+            .iter()
+            .copied()
+            .filter(|&prime| low <= prime && prime <= high)
+            .collect();
+        push_wheel_primes(
+            &self.bytes[low / 30..=high / 30],
+            low / 30,
+            low,
+            high,
+            &mut primes,
+        );
+
+        primes
+    }
+
+    /// Counts the primes less than or equal to `number`, extending the table to it if needed.
+    pub fn count_up_to(&mut self, number: usize) -> usize {
+        self.extend_to(number);
+
+        let small: usize = WHEEL_PRIMES
+            .iter()
+            .filter(|&&prime| prime <= number)
+            .count();
+        let full: usize = self.bytes[..number / 30]
+            .iter()
+            .map(|byte| byte.count_ones() as usize)
+            .sum();
+        let partial: u8 = self.bytes[number / 30] & mask_up_to(number % 30);
+
+        small + full + partial.count_ones() as usize
+    }
+
+    /// Returns the `nth` prime, starting from 1, extending the table up to Rosser's bound
+    /// `n (ln n + ln ln n)` if needed.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `nth` is 0.
+    pub fn nth(&mut self, nth: usize) -> usize {
+        if nth == 0 {
+            panic!("eratosthenes::sieve::Sieve::nth counts primes starting from 1.");
+        }
+        if nth <= WHEEL_PRIMES.len() {
+            return WHEEL_PRIMES[nth - 1];
+        }
+
+        let n: f64 = nth as f64;
+        let bound: usize = if nth < 6 {
+            11
+        } else {
+            (n * (n.ln() + n.ln().ln())) as usize + 1
+        };
+        self.extend_to(bound);
+
+        let mut remaining: usize = nth - WHEEL_PRIMES.len();
+        for (index, &byte) in self.bytes.iter().enumerate() {
+            let count: usize = byte.count_ones() as usize;
+            if remaining > count {
+                remaining -= count;
+                continue;
+            }
+
+            let mut bits: u8 = byte;
+            for _ in 1..remaining {
+                bits &= bits - 1;
+            }
+            return index * 30 + RESIDUES[bits.trailing_zeros() as usize];
+        }
+
+        unreachable!("Rosser's bound always covers the nth prime.")
+    }
+
+    /// Returns the smallest prime factor of `number`, or 1 for the number 1. The table is extended
+    /// to the square root of the number if needed.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the input `number` is 0.
+    pub fn smallest_factor(&mut self, number: usize) -> usize {
+        if number == 0 {
+            panic!("eratosthenes::sieve::Sieve::smallest_factor cannot work with 0.");
+        }
+        if number == 1 {
+            return 1;
+        }
+        if let Some(&prime) = WHEEL_PRIMES.iter().find(|&&prime| number % prime == 0) {
+            return prime;
+        }
+
+        let root: usize = crate::isqrt(number as u128) as usize;
+        self.extend_to(root);
+        for (index, &byte) in self.bytes[..=root / 30].iter().enumerate() {
+            let mut bits: u8 = byte;
+            while bits != 0 {
+                let candidate: usize = index * 30 + RESIDUES[bits.trailing_zeros() as usize];
+                if candidate > root {
+                    return number;
+                }
+                if number % candidate == 0 {
+                    return candidate;
+                }
+                bits &= bits - 1;
+            }
+        }
+
+        number
+    }
+
+    /// Writes the table to a file, so it can be read back with [`Sieve::load`] instead of being
+    /// sieved again.
+    ///
+    /// The file starts with a header of little-endian fields: the magic bytes `ERATOSIV`, the
+    /// format version (`u16`), the wheel modulus 30 (`u16`), the limit (`u64`), the number of
+    /// bitmap bytes (`u64`) and the 64-bit FNV-1a checksum of the limit's bytes followed by the
+    /// bitmap (`u64`). The bitmap follows, one byte per 30 integers.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the file cannot be created or written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eratosthenes::sieve::Sieve;
+    ///
+    /// let path = std::env::temp_dir().join("eratosthenes_save_example.bin");
+    /// Sieve::with_limit(1_000_000).save(&path).unwrap();
+    ///
+    /// let mut sieve = Sieve::load(&path).unwrap();
+    /// assert_eq!(sieve.limit(), 1_000_000);
+    /// assert_eq!(sieve.count_up_to(1_000_000), 78_498);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut file: BufWriter<File> = BufWriter::new(File::create(path)?);
+
+        file.write_all(&FILE_MAGIC)?;
+        file.write_all(&FILE_VERSION.to_le_bytes())?;
+        file.write_all(&FILE_WHEEL.to_le_bytes())?;
+        let limit: [u8; 8] = (self.limit as u64).to_le_bytes();
+        file.write_all(&limit)?;
+        file.write_all(&(self.bytes.len() as u64).to_le_bytes())?;
+        file.write_all(&fnv1a(&[&limit, &self.bytes]).to_le_bytes())?;
+        file.write_all(&self.bytes)?;
+
+        file.flush()
+    }
+
+    /// Reads a table written by [`Sieve::save`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the file cannot be read, and an error of kind
+    /// [`io::ErrorKind::InvalidData`] if it is not a sieve file, was written by an unsupported
+    /// version, uses another wheel, covers more integers than fit in a `usize`, does not have
+    /// exactly the size its header declares, or fails its checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eratosthenes::sieve::Sieve;
+    ///
+    /// let path = std::env::temp_dir().join("eratosthenes_load_example.bin");
+    /// std::fs::write(&path, b"not a sieve").unwrap();
+    ///
+    /// let error = Sieve::load(&path).unwrap_err();
+    /// assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file: File = File::open(path)?;
+        let file_bytes: u64 = file.metadata()?.len();
+        let mut file: BufReader<File> = BufReader::new(file);
+
+        let mut magic: [u8; 8] = [0; 8];
+        file.read_exact(&mut magic)?;
+        if magic != FILE_MAGIC {
+            return Err(invalid_data("not an eratosthenes sieve file"));
+        }
+        if read_u16(&mut file)? != FILE_VERSION {
+            return Err(invalid_data("unsupported sieve file version"));
+        }
+        if read_u16(&mut file)? != FILE_WHEEL {
+            return Err(invalid_data("unsupported sieve wheel"));
+        }
+
+        let stored_limit: u64 = read_u64(&mut file)?;
+        let limit: usize = usize::try_from(stored_limit)
+            .map_err(|_| invalid_data("sieve limit does not fit in usize"))?;
+        let length: u64 = read_u64(&mut file)?;
+        if limit == 0 || length != (limit / 30 + 1) as u64 {
+            return Err(invalid_data("sieve bitmap size does not match its limit"));
+        }
+        let checksum: u64 = read_u64(&mut file)?;
+
+        // The declared size is checked against the file before anything is allocated, so a forged
+        // header cannot request more memory than the file holds.
+        if file_bytes.checked_sub(FILE_HEADER_BYTES) != Some(length) {
+            return Err(invalid_data(
+                "sieve bitmap size does not match the file size",
+            ));
+        }
+
+        let mut bytes: Vec<u8> = vec![0; limit / 30 + 1];
+        file.read_exact(&mut bytes)?;
+        if file.read(&mut [0])? != 0 {
+            return Err(invalid_data("sieve file has trailing bytes"));
+        }
+        if fnv1a(&[&stored_limit.to_le_bytes(), &bytes]) != checksum {
+            return Err(invalid_data("sieve bitmap checksum mismatch"));
+        }
+
+        Ok(Sieve { limit, bytes })
+    }
+}
+
+/// Hashes the concatenation of byte slices with the 64-bit FNV-1a function.
+fn fnv1a(parts: &[&[u8]]) -> u64 {
+    parts
+        .iter()
+        .flat_map(|part| part.iter())
+        .fold(0xcbf2_9ce4_8422_2325, |hash: u64, &byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+        })
+}
+
+fn read_u16(reader: &mut impl Read) -> io::Result<u16> {
+    let mut buffer: [u8; 2] = [0; 2];
+    reader.read_exact(&mut buffer)?;
+    Ok(u16::from_le_bytes(buffer))
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    let mut buffer: [u8; 8] = [0; 8];
+    reader.read_exact(&mut buffer)?;
+    Ok(u64::from_le_bytes(buffer))
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+impl Default for Sieve {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the process-wide [`Sieve`], created empty on first use and shared between threads.
+///
+/// The sieve stays locked while the returned guard is alive, so keep it only for the queries
+/// that need it.
+///
+/// # Examples
+///
+/// ```
+/// use eratosthenes::sieve::shared_sieve;
+///
+/// assert!(shared_sieve().is_prime(7_919));
+/// ```
+pub fn shared_sieve() -> MutexGuard<'static, Sieve> {
+    static SHARED: OnceLock<Mutex<Sieve>> = OnceLock::new();
+
+    SHARED
+        .get_or_init(|| Mutex::new(Sieve::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn sieve_test() {
+        let mut sieve: Sieve = Sieve::with_limit(100);
+        assert_eq!(sieve.limit(), 100);
+        assert_eq!(sieve.primes_in(..=100), crate::sequences::primes(100));
+        assert_eq!(sieve.primes_in(0..2), vec![]);
+        assert_eq!(sieve.primes_in(3..3), vec![]);
+        assert_eq!(sieve.primes_in(2..=2), vec![2]);
+
+        assert_eq!(
+            sieve.primes_in(..=1_000_000),
+            crate::sequences::primes(1_000_000)
+        );
+        assert!(sieve.limit() >= 1_000_000);
+    }
+
+    #[test]
+    fn is_prime_test() {
+        let mut sieve: Sieve = Sieve::new();
+        let expected: Vec<usize> = crate::sequences::primes(10_000);
+        let found: Vec<usize> = (0..=10_000).filter(|&n| sieve.is_prime(n)).collect();
+        assert_eq!(found, expected);
+
+        assert!(sieve.is_prime(1_000_000_000_039));
+        assert!(!sieve.is_prime(1_000_000_007 * 1_000_000_009));
+        assert!(sieve.limit() < 1_000_000);
+    }
+
+    #[test]
+    fn count_up_to_test() {
+        let mut sieve: Sieve = Sieve::new();
+        assert_eq!(sieve.count_up_to(0), 0);
+        assert_eq!(sieve.count_up_to(2), 1);
+        assert_eq!(sieve.count_up_to(3), 2);
+        assert_eq!(sieve.count_up_to(4), 2);
+        assert_eq!(sieve.count_up_to(5), 3);
+        assert_eq!(sieve.count_up_to(6), 3);
+        assert_eq!(sieve.count_up_to(7), 4);
+        assert_eq!(sieve.count_up_to(128), 31);
+        assert_eq!(sieve.count_up_to(129), 31);
+        assert_eq!(sieve.count_up_to(131), 32);
+        assert_eq!(sieve.count_up_to(10_000_000), 664_579);
+    }
+
+    #[test]
+    fn nth_test() {
+        let mut sieve: Sieve = Sieve::new();
+        for (index, &prime) in crate::sequences::primes(10_000).iter().enumerate() {
+            assert_eq!(sieve.nth(index + 1), prime);
+        }
+        assert_eq!(sieve.nth(1_000_000), 15_485_863);
+    }
+
+    #[test]
+    fn smallest_factor_test() {
+        let mut sieve: Sieve = Sieve::new();
+        let table: Vec<usize> = crate::sieve::smallest_prime_factors(10_000);
+        for (number, &factor) in table.iter().enumerate().skip(1) {
+            assert_eq!(sieve.smallest_factor(number), factor);
+        }
+        assert_eq!(sieve.smallest_factor(1_000_000_007), 1_000_000_007);
+        assert_eq!(sieve.smallest_factor(999_983 * 1_000_003), 999_983);
+    }
+
+    #[test]
+    fn save_load_test() {
+        let path: std::path::PathBuf = std::env::temp_dir().join(format!(
+            "eratosthenes_save_load_test_{}.bin",
+            std::process::id()
+        ));
+
+        let sieve: Sieve = Sieve::with_limit(1_000_000);
+        sieve.save(&path).unwrap();
+        let mut loaded: Sieve = Sieve::load(&path).unwrap();
+        assert_eq!(loaded.limit(), sieve.limit());
+        assert_eq!(loaded.bytes, sieve.bytes);
+        assert_eq!(loaded.count_up_to(2_000_000), 148_933);
+
+        Sieve::new().save(&path).unwrap();
+        assert_eq!(
+            Sieve::load(&path).unwrap().primes_in(..=10),
+            vec![2, 3, 5, 7]
+        );
+
+        let mut contents: Vec<u8> = Vec::new();
+        sieve.save(&path).unwrap();
+        File::open(&path)
+            .unwrap()
+            .read_to_end(&mut contents)
+            .unwrap();
+        let corrupt = |index: usize| {
+            let mut corrupted: Vec<u8> = contents.clone();
+            corrupted[index] ^= 1;
+            std::fs::write(&path, corrupted).unwrap();
+            Sieve::load(&path).unwrap_err().kind()
+        };
+        assert_eq!(corrupt(0), io::ErrorKind::InvalidData);
+        assert_eq!(corrupt(8), io::ErrorKind::InvalidData);
+        assert_eq!(corrupt(10), io::ErrorKind::InvalidData);
+        assert_eq!(corrupt(12), io::ErrorKind::InvalidData);
+        assert_eq!(corrupt(contents.len() - 1), io::ErrorKind::InvalidData);
+
+        let load = |bytes: &[u8]| {
+            std::fs::write(&path, bytes).unwrap();
+            Sieve::load(&path).unwrap_err().kind()
+        };
+        assert_eq!(
+            load(&contents[..contents.len() - 1]),
+            io::ErrorKind::InvalidData
+        );
+        assert_eq!(load(&contents[..20]), io::ErrorKind::UnexpectedEof);
+        assert_eq!(
+            load(&[contents.as_slice(), &[0]].concat()),
+            io::ErrorKind::InvalidData
+        );
+
+        // A header whose limit and length agree but which the file cannot back.
+        let mut forged: Vec<u8> = contents[..FILE_HEADER_BYTES as usize].to_vec();
+        let limit: u64 = 1 << 60;
+        forged[12..20].copy_from_slice(&limit.to_le_bytes());
+        forged[20..28].copy_from_slice(&(limit / 30 + 1).to_le_bytes());
+        assert_eq!(load(&forged), io::ErrorKind::InvalidData);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn shared_sieve_test() {
+        let handles: Vec<std::thread::JoinHandle<usize>> = (0..4)
+            .map(|index| std::thread::spawn(move || shared_sieve().count_up_to(1_000 * index)))
+            .collect();
+        let counts: Vec<usize> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert_eq!(counts, vec![0, 168, 303, 430]);
+    }
+}
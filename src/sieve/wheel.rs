@@ -0,0 +1,136 @@
+/*!
+Storage of sieves on the mod-30 wheel.
+
+Only the integers coprime to 30 can be primes above 5, and there are 8 of them in every block of
+30 integers. Byte `k` of a wheel bitset covers the integers `30k..30k + 30`, its bit `j` standing
+for `30k + RESIDUES[j]`, so a sieve needs one byte per 30 integers.
+*/
+
+/// The primes dividing the wheel size, which are not represented in a wheel bitset.
+pub(crate) const WHEEL_PRIMES: [usize; 3] = [2, 3, 5];
+
+/// The residues modulo 30 that are coprime to 30, in bit order.
+pub(crate) const RESIDUES: [usize; 8] = [1, 7, 11, 13, 17, 19, 23, 29];
+
+/// The distance from each residue to the next one, wrapping around to 31.
+const GAPS: [usize; 8] = [6, 4, 2, 4, 2, 4, 6, 2];
+
+/// Not a bit index, for residues that share a factor with 30.
+const NONE: u8 = 8;
+
+/// The bit standing for each residue modulo 30, or `NONE`.
+const BITS: [u8; 30] = [
+    NONE, 0, NONE, NONE, NONE, NONE, NONE, 1, NONE, NONE, NONE, 2, NONE, 3, NONE, NONE, NONE, 4,
+    NONE, 5, NONE, NONE, NONE, 6, NONE, NONE, NONE, NONE, NONE, 7,
+];
+
+/// Returns the byte and the bit mask standing for `number`, or `None` if it shares a factor
+/// with 30.
+pub(crate) fn position(number: usize) -> Option<(usize, u8)> {
+    match BITS[number % 30] {
+        NONE => None,
+        bit => Some((number / 30, 1 << bit)),
+    }
+}
+
+/// Returns the mask of the bits standing for the residues less than or equal to `residue`.
+pub(crate) fn mask_up_to(residue: usize) -> u8 {
+    RESIDUES
+        .iter()
+        .enumerate()
+        .filter(|(_, &candidate)| candidate <= residue)
+        .fold(0, |mask, (bit, _)| mask | 1 << bit)
+}
+
+/// Sieves the wheel bitset `bytes`, whose first byte is byte `first` of the whole wheel, so that
+/// a bit is set exactly when its integer has no prime factor among the base primes other than
+/// itself. The base primes must include every prime up to the square root of the last integer
+/// of the segment.
+pub(crate) fn sieve_wheel_segment(bytes: &mut [u8], first: usize, base_primes: &[usize]) {
+    bytes.fill(u8::MAX);
+    if first == 0 {
+        // 1 is not a prime.
+        bytes[0] &= !1;
+    }
+
+    let start: usize = first * 30;
+    let end: usize = start.saturating_add(bytes.len() * 30 - 1);
+
+    for &prime in base_primes.iter().filter(|&&prime| prime > 5) {
+        if prime * prime > end {
+            break;
+        }
+
+        // Cross out prime * q for the q coprime to 30, starting from max(prime, start / prime).
+        let mut q: usize = prime.max(start / prime + usize::from(start % prime != 0));
+        while BITS[q % 30] == NONE {
+            q += 1;
+        }
+        let mut step: usize = BITS[q % 30] as usize;
+
+        while let Some(multiple) = prime.checked_mul(q).filter(|&multiple| multiple <= end) {
+            let offset: usize = multiple - start;
+            bytes[offset / 30] &= !(1 << BITS[offset % 30]);
+            q += GAPS[step];
+            step = (step + 1) % 8;
+        }
+    }
+}
+
+/// Appends to `primes` the integers within `low..=high` whose bit is set in `bytes`, whose
+/// first byte is byte `first` of the whole wheel.
+pub(crate) fn push_wheel_primes(
+    bytes: &[u8],
+    first: usize,
+    low: usize,
+    high: usize,
+    primes: &mut Vec<usize>,
+) {
+    for (index, &byte) in bytes.iter().enumerate() {
+        let base: usize = (first + index) * 30;
+        let mut bits: u8 = byte;
+        while bits != 0 {
+            let number: usize = match base.checked_add(RESIDUES[bits.trailing_zeros() as usize]) {
+                Some(number) => number,
+                None => return,
+            };
+            bits &= bits - 1;
+            if low <= number && number <= high {
+                primes.push(number);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn sieve_wheel_segment_test() {
+        let base_primes: Vec<usize> = crate::sequences::primes::small_primes(100);
+        let mut bytes: Vec<u8> = vec![0; 334];
+        sieve_wheel_segment(&mut bytes, 0, &base_primes);
+
+        let mut primes: Vec<usize> = WHEEL_PRIMES.to_vec();
+        push_wheel_primes(&bytes, 0, 0, 10_000, &mut primes);
+        assert_eq!(primes, crate::sequences::primes::small_primes(10_000));
+
+        let mut middle: Vec<usize> = Vec::new();
+        sieve_wheel_segment(&mut bytes[..10], 100, &base_primes);
+        push_wheel_primes(&bytes[..10], 100, 3_001, 3_290, &mut middle);
+        assert_eq!(middle, crate::sequences::primes_in_range(3_001, 3_290));
+    }
+
+    #[test]
+    fn position_test() {
+        assert_eq!(position(0), None);
+        assert_eq!(position(1), Some((0, 1)));
+        assert_eq!(position(49), Some((1, 1 << 5)));
+        assert_eq!(position(35), None);
+        assert_eq!(mask_up_to(0), 0);
+        assert_eq!(mask_up_to(12), 0b111);
+        assert_eq!(mask_up_to(29), u8::MAX);
+    }
+}
@@ -1,6 +1,9 @@
+#![allow(clippy::single_component_path_imports)]
+
 use eratosthenes;
 
 #[test]
+#[allow(clippy::unnecessary_mut_passed)]
 fn euler_problem1() {
     let limit: usize = 500;
 
@@ -44,3 +47,89 @@ fn euler_problem2() {
 
     assert_eq!(result, 1089154);
 }
+
+#[test]
+fn euler_problem3() {
+    let factors: Vec<usize> = eratosthenes::factors_prime(600_851_475_143);
+    let result: usize = *factors.last().unwrap();
+
+    assert_eq!(result, 6857);
+}
+
+#[test]
+fn euler_problem7() {
+    let result: usize = eratosthenes::sequences::nth_prime(10_001);
+
+    assert_eq!(result, 104_743);
+}
+
+#[test]
+fn euler_problem10() {
+    let result: u128 = eratosthenes::counting::prime_sum(2_000_000);
+
+    assert_eq!(result, 142_913_828_922);
+}
+
+#[test]
+fn euler_problem12() {
+    let result: usize = (1..)
+        .map(|n: usize| n * (n + 1) / 2)
+        .find(|&triangular| {
+            eratosthenes::factorization::Factorization::from(triangular)
+                .divisors_iter()
+                .nth(500)
+                .is_some()
+        })
+        .unwrap();
+
+    assert_eq!(result, 76_576_500);
+}
+
+#[test]
+fn euler_problem21() {
+    let result: usize = (1..10_000)
+        .filter(|&number| eratosthenes::arithmetic_functions::is_amicable(number))
+        .sum();
+
+    assert_eq!(result, 31_626);
+}
+
+#[test]
+fn euler_problem23() {
+    let limit: usize = 28_123;
+    let divisor_sums: Vec<usize> = eratosthenes::arithmetic_functions::divisor_sum_table(limit);
+    let abundant: Vec<usize> = (1..=limit)
+        .filter(|&number| divisor_sums[number] - number > number)
+        .collect();
+
+    let mut expressible: Vec<bool> = vec![false; limit + 1];
+    for (index, &a) in abundant.iter().enumerate() {
+        for &b in abundant[index..].iter() {
+            if a + b > limit {
+                break;
+            }
+            expressible[a + b] = true;
+        }
+    }
+
+    let result: usize = (1..=limit).filter(|&number| !expressible[number]).sum();
+
+    assert_eq!(result, 4_179_871);
+}
+
+#[test]
+fn euler_problem69() {
+    let totients: Vec<usize> = eratosthenes::arithmetic_functions::totient_sieve(1_000_000);
+    let result: usize = (2..totients.len())
+        .max_by(|&a, &b| (a * totients[b]).cmp(&(b * totients[a])))
+        .unwrap();
+
+    assert_eq!(result, 510_510);
+}
+
+#[test]
+fn euler_problem72() {
+    let result: u128 = eratosthenes::arithmetic_functions::totient_sum(1_000_000) - 1;
+
+    assert_eq!(result, 303_963_552_391);
+}